@@ -0,0 +1,211 @@
+use std::ops::{Index, IndexMut};
+use crate::ParseError;
+
+/// A cell of a grid, counted from the top left corner.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Pos {
+        Pos { row, col }
+    }
+}
+
+/// A step from one cell to another, which may leave the grid.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Offset {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Offset {
+    pub const NORTH: Offset = Offset::new(-1, 0);
+    pub const SOUTH: Offset = Offset::new(1, 0);
+    pub const WEST: Offset = Offset::new(0, -1);
+    pub const EAST: Offset = Offset::new(0, 1);
+
+    /// The 4-neighborhood.
+    pub const ORTHOGONAL: [Offset; 4] = [Offset::NORTH, Offset::WEST, Offset::EAST, Offset::SOUTH];
+
+    /// The 8-neighborhood, row by row from the top left.
+    pub const SURROUNDING: [Offset; 8] = [
+        Offset::new(-1, -1), Offset::new(-1, 0), Offset::new(-1, 1),
+        Offset::new(0, -1), Offset::new(0, 1),
+        Offset::new(1, -1), Offset::new(1, 0), Offset::new(1, 1),
+    ];
+
+    pub const fn new(row: isize, col: isize) -> Offset {
+        Offset { row, col }
+    }
+}
+
+/// A rectangular map of cells, stored row by row.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses the lines of `text` up to the first empty one, one character per cell.
+    ///
+    /// `parse_cell` maps every character to its cell; characters it rejects are reported as not being
+    /// `expected`.
+    pub fn parse(text: &str, expected: &str, parse_cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        Grid::parse_rows(text, |line| {
+            line.char_indices()
+                .map(|(idx, c)| parse_cell(c).ok_or_else(|| ParseError::at(line, &line[idx..], expected)))
+                .collect()
+        })
+    }
+
+    /// Parses the lines of `text` up to the first empty one with `parse_row`, which must produce rows
+    /// of equal width.
+    pub fn parse_rows(text: &str, parse_row: impl Fn(&str) -> Result<Vec<T>, ParseError>) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (idx, line) in text.lines().enumerate().take_while(|(_, line)| !line.is_empty()) {
+            let mut row = parse_row(line).map_err(|err| err.offset_lines(idx))?;
+            if *width.get_or_insert(row.len()) != row.len() {
+                return Err(ParseError::at(text, &line[line.len()..], format!("a row of {} cells", width.unwrap())));
+            }
+            cells.append(&mut row);
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid { width, height, cells }),
+            _ => Err(ParseError::at(text, text, "a row of cells")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    /// The cell `offset` away from `pos`, unless that is outside the grid.
+    pub fn step(&self, pos: Pos, offset: Offset) -> Option<Pos> {
+        let row = pos.row.checked_add_signed(offset.row)?;
+        let col = pos.col.checked_add_signed(offset.col)?;
+        Some(Pos::new(row, col)).filter(|pos| self.contains(*pos))
+    }
+
+    /// The cells `offsets` away from `pos` that are inside the grid, in the order of `offsets`.
+    pub fn neighbors<'a>(&'a self, pos: Pos, offsets: &'a [Offset]) -> impl Iterator<Item = Pos> + 'a {
+        offsets.iter().filter_map(move |offset| self.step(pos, *offset))
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &Offset::ORTHOGONAL)
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbors(pos, &Offset::SURROUNDING)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(Pos, &T) -> U) -> Grid<U> {
+        let cells = self.iter().map(|(pos, cell)| f(pos, cell)).collect();
+        Grid { width: self.width, height: self.height, cells }
+    }
+
+    /// Renders the grid back to text, one line per row.
+    pub fn render(&self, render_cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&render_cell).chain(Some('\n')).collect::<String>())
+            .collect()
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{:?} is outside of a {}x{} grid", pos, self.width, self.height);
+        &mut self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse("123\n456\n", "a digit", |c| c.to_digit(10)).unwrap();
+        let values = |positions: Vec<Pos>| positions.into_iter().map(|pos| grid[pos]).collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 4], values(grid.neighbors4(Pos::new(0, 0)).collect()));
+        assert_eq!(vec![2, 3, 5], values(grid.neighbors8(Pos::new(1, 2)).collect()));
+        assert_eq!(vec![1, 2, 3, 4, 6], values(grid.neighbors8(Pos::new(1, 1)).collect()));
+        assert_eq!(None, grid.step(Pos::new(1, 2), Offset::SOUTH));
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = Grid::parse("#.\n.#\n\nrest", "`#` or `.`", |c| Some(c).filter(|c| "#.".contains(*c))).unwrap();
+
+        assert_eq!((2, 2), (grid.width(), grid.height()));
+        assert_eq!(vec![&'#', &'.'], grid.column(0).collect::<Vec<_>>());
+        assert_eq!(&['.', '#'], grid.row(1));
+        assert_eq!("#.\n.#\n", grid.render(|c| *c));
+
+        assert_eq!((2, 2), Grid::parse("#.\n.x\n", "", |c| Some(c).filter(|c| "#.".contains(*c))).map_err(|err| (err.line, err.column)).unwrap_err());
+        assert_eq!((2, 2), Grid::parse("#.\n.\n", "", Some).map_err(|err| (err.line, err.column)).unwrap_err());
+        assert!(Grid::parse("", "", Some).is_err());
+    }
+}
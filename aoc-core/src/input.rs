@@ -0,0 +1,78 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Picks the input for `day`: an explicit `path` wins over the `AOC_DAY<N>_INPUT` environment
+    /// variable, which wins over the bundled `day<N>/data/input.txt`. `-` stands for stdin.
+    pub fn resolve(day: u8, path: Option<&str>) -> InputSource {
+        let explicit = path.map(str::to_string).or_else(|| env::var(env_var(day)).ok());
+        match explicit.as_deref() {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::File(default_path(day)),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut file_content = String::new();
+                io::stdin().read_to_string(&mut file_content)?;
+                Ok(file_content)
+            }
+            InputSource::File(path) => read_to_string(path),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn env_var(day: u8) -> String {
+    format!("AOC_DAY{}_INPUT", day)
+}
+
+/// The root of the workspace, so bundled files are found regardless of the working directory.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+/// The bundled input of `day`.
+pub fn default_path(day: u8) -> PathBuf {
+    workspace_dir().join(format!("day{}", day)).join("data").join("input.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(InputSource::Stdin, InputSource::resolve(1, Some("-")));
+        assert_eq!(InputSource::File(PathBuf::from("mine.txt")), InputSource::resolve(1, Some("mine.txt")));
+
+        env::set_var(env_var(99), "theirs.txt");
+        assert_eq!(InputSource::File(PathBuf::from("theirs.txt")), InputSource::resolve(99, None));
+        assert_eq!(InputSource::File(PathBuf::from("mine.txt")), InputSource::resolve(99, Some("mine.txt")));
+
+        assert_eq!(InputSource::File(default_path(98)), InputSource::resolve(98, None));
+        assert!(default_path(98).ends_with("day98/data/input.txt"));
+    }
+}
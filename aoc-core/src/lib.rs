@@ -0,0 +1,239 @@
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use clap::Parser;
+
+mod grid;
+mod input;
+mod parse;
+
+pub use grid::{Grid, Offset, Pos};
+pub use input::{default_path, env_var, workspace_dir, InputSource};
+pub use parse::{parse_lines, parse_number, ParseError};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(u64::from(value))
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(u64::try_from(value).unwrap())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+/// A day of the puzzle calendar: parses its input once and solves both parts on it.
+///
+/// Parts that are not implemented keep the default, return `None` and are left out of `PARTS`.
+pub trait Solution {
+    const DAY: u8;
+    const PARTS: &'static [u8] = &[1, 2];
+
+    /// The parsed input, which may borrow from the text it was parsed from.
+    type Input<'a>;
+
+    fn parse(file_content: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(_input: &Self::Input<'_>) -> Option<Answer> {
+        None
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Option<Answer> {
+        None
+    }
+
+    /// Solves `part` together with the problems with the input that did not stop it from being
+    /// solved, such as skipped lines, so that only the parts asked for are warned about.
+    fn solve_part(input: &Self::Input<'_>, part: u8) -> Solved {
+        let answer = match part {
+            1 => Self::part1(input),
+            2 => Self::part2(input),
+            _ => None
+        };
+        Solved { answer, warnings: vec![] }
+    }
+}
+
+/// The answer to one part, if it has one, and what was wrong with the input along the way.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Solved {
+    pub answer: Option<Answer>,
+    pub warnings: Vec<String>,
+}
+
+/// Command line arguments shared by every day's binary.
+#[derive(Parser, Debug, Clone)]
+#[command(about = "Solves one day of Advent of Code 2023", long_about = None)]
+pub struct InputArgs {
+    /// Puzzle input file or `-` for stdin [default: $AOC_DAY<N>_INPUT, then the bundled input]
+    pub input: Option<String>,
+    /// Solve only this part
+    #[arg(short, long)]
+    pub part: Option<u8>,
+}
+
+impl InputArgs {
+    pub fn source(&self, day: u8) -> InputSource {
+        InputSource::resolve(day, self.input.as_deref())
+    }
+
+    /// The part asked for, or every part of `S` if none was.
+    pub fn parts<S: Solution>(&self) -> Result<Vec<u8>, String> {
+        match self.part {
+            Some(part) if !S::PARTS.contains(&part) => Err(format!("Day {} has no part {}", S::DAY, part)),
+            Some(part) => Ok(vec![part]),
+            None => Ok(S::PARTS.to_vec()),
+        }
+    }
+}
+
+/// Entry point of a day's binary: solves the input named on the command line.
+pub fn main<S: Solution>() -> ExitCode {
+    let args = InputArgs::parse();
+    match args.parts::<S>() {
+        Ok(parts) => run::<S>(&args.source(S::DAY), &parts),
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads the input from `source`, solves `parts` and prints one answer per line.
+pub fn run<S: Solution>(source: &InputSource, parts: &[u8]) -> ExitCode {
+    let solved = read(source).and_then(|file_content| {
+        let input = parse::<S>(source, &file_content)?;
+        print_answers::<S>(&input, parts);
+        Ok(())
+    });
+    match solved {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads the input from `source`, or describes why that failed.
+pub fn read(source: &InputSource) -> Result<String, String> {
+    source.read().map_err(|err| format!("{}: {}", source, err))
+}
+
+/// Parses `file_content` read from `source`, or describes why that failed.
+pub fn parse<'a, S: Solution>(source: &InputSource, file_content: &'a str) -> Result<S::Input<'a>, String> {
+    S::parse(file_content).map_err(|err| err.in_file(source).to_string())
+}
+
+/// Prints one line per part on stdout, `-` for a part without an answer, and the warnings of
+/// those parts on stderr. Stops quietly once stdout is closed, such as by `head`.
+pub fn print_answers<S: Solution>(input: &S::Input<'_>, parts: &[u8]) {
+    let mut stdout = std::io::stdout().lock();
+    for part in parts {
+        let solved = S::solve_part(input, *part);
+        for warning in &solved.warnings {
+            eprintln!("warning: {}", warning);
+        }
+        let written = match solved.answer {
+            Some(answer) => writeln!(stdout, "{}", answer),
+            None => writeln!(stdout, "-"),
+        };
+        if written.and_then(|_| stdout.flush()).is_err() {
+            return;
+        }
+    }
+}
+
+pub fn solve<S: Solution>(input: &S::Input<'_>, part: u8) -> Option<Answer> {
+    S::solve_part(input, part).answer
+}
+
+/// The answer to one part together with the time it took to solve it.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Everything solving one day produced: the answers with their timings and the day's warnings.
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+    pub warnings: Vec<String>,
+}
+
+impl DayResult {
+    pub fn answer(&self, part: u8) -> Option<Answer> {
+        self.parts.iter().find(|result| result.part == part).map(|result| result.answer)
+    }
+}
+
+/// A type-erased [`Solution`], so days with different inputs can be kept in one list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub parts: &'static [u8],
+    solve: fn(&str, &[u8]) -> Result<DayResult, ParseError>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Day {
+        Day { day: S::DAY, parts: S::PARTS, solve: solve_parts::<S> }
+    }
+
+    /// Parses `file_content` once and solves those of the requested parts that this day implements.
+    pub fn solve(&self, file_content: &str, parts: &[u8]) -> Result<DayResult, ParseError> {
+        (self.solve)(file_content, parts)
+    }
+}
+
+fn solve_parts<S: Solution>(file_content: &str, parts: &[u8]) -> Result<DayResult, ParseError> {
+    let now = Instant::now();
+    let input = S::parse(file_content)?;
+    let parse_elapsed = now.elapsed();
+
+    let mut results = vec![];
+    let mut warnings = vec![];
+    for part in parts.iter().filter(|part| S::PARTS.contains(part)) {
+        let now = Instant::now();
+        let solved = S::solve_part(&input, *part);
+        let elapsed = now.elapsed();
+        results.extend(solved.answer.map(|answer| PartResult { day: S::DAY, part: *part, answer, elapsed }));
+        warnings.extend(solved.warnings);
+    }
+    Ok(DayResult { day: S::DAY, parse_elapsed, parts: results, warnings })
+}
@@ -0,0 +1,115 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A malformed puzzle input, located by line and column so it can be shown next to the offending line.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub excerpt: String,
+}
+
+impl ParseError {
+    /// An error at the start of `fragment`, which must be a slice of `text`.
+    ///
+    /// Line and column are 1-based and relative to `text`; fragments that are not part of `text` are
+    /// reported at its end.
+    pub fn at(text: &str, fragment: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|offset| *offset <= text.len())
+            .unwrap_or(text.len());
+
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |pos| pos + 1);
+        let line_end = text[offset..].find('\n').map_or(text.len(), |pos| offset + pos);
+
+        ParseError {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            excerpt: text[line_start..line_end].trim_end_matches('\r').to_string(),
+        }
+    }
+
+    /// An error just past the end of `text`, for input that stops before something required.
+    pub fn at_end(text: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(text, &text[text.len()..], expected)
+    }
+
+    /// Moves the error down by `lines`, for text that started below the first line of the file.
+    pub fn offset_lines(self, lines: usize) -> ParseError {
+        ParseError { line: self.line + lines, ..self }
+    }
+
+    pub fn in_file(self, file: impl Display) -> ParseError {
+        ParseError { file: Some(file.to_string()), ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "error: expected {}", self.expected)?;
+        match &self.file {
+            Some(file) => writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?,
+            None => writeln!(f, "{}--> {}:{}", gutter, self.line, self.column)?,
+        }
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.excerpt)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every non-empty line with `parse_line`, reporting errors at their line in `file_content`.
+pub fn parse_lines<'a, T>(file_content: &'a str, parse_line: impl Fn(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    file_content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| parse_line(line).map_err(|err| err.offset_lines(idx)))
+        .collect()
+}
+
+/// Parses `fragment` of `text` as a number.
+pub fn parse_number<T: std::str::FromStr>(text: &str, fragment: &str) -> Result<T, ParseError> {
+    fragment.parse::<T>().map_err(|_| ParseError::at(text, fragment, "a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let text = "first line\nsecond line\nthird";
+        let err = ParseError::at(text, &text[18..], "a digit");
+
+        assert_eq!(2, err.line);
+        assert_eq!(8, err.column);
+        assert_eq!("second line", err.excerpt);
+        assert_eq!(ParseError { line: 4, ..err.clone() }, err.clone().offset_lines(2));
+
+        assert_eq!("error: expected a digit
+ --> input.txt:2:8
+  |
+2 | second line
+  |        ^", err.in_file("input.txt").to_string());
+
+        let end = ParseError::at_end(text, "a newline");
+        assert_eq!((3, 6, "third".to_string()), (end.line, end.column, end.excerpt));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let result = parse_lines("1\n\n2\nx\n", |line| parse_number::<u32>(line, line));
+
+        assert_eq!(Ok(vec![1]), parse_lines("1\n\n", |line| parse_number::<u32>(line, line)));
+        assert_eq!(4, result.unwrap_err().line);
+    }
+}
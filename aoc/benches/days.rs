@@ -0,0 +1,48 @@
+use std::fs::read_to_string;
+use std::time::Duration;
+use aoc_core::{default_path, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmarks parse, part 1 and part 2 of `S` separately on its bundled input, as `dayN/parse`,
+/// `dayN/part1` and `dayN/part2`, so each step can be compared across commits on its own.
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let path = default_path(S::DAY);
+    let file_content = read_to_string(&path).unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+    let input = S::parse(&file_content).unwrap_or_else(|err| panic!("{}", err.in_file(path.display())));
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&file_content))));
+    if S::PARTS.contains(&1) {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    }
+    if S::PARTS.contains(&2) {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c);
+    bench_day::<day2::Day2>(c);
+    bench_day::<day3::Day3>(c);
+    bench_day::<day4::Day4>(c);
+    bench_day::<day5::Day5>(c);
+    bench_day::<day6::Day6>(c);
+    bench_day::<day7::Day7>(c);
+    bench_day::<day8::Day8>(c);
+    bench_day::<day9::Day9>(c);
+    bench_day::<day11::Day11>(c);
+}
+
+/// Day 10's loop search takes seconds per part, so it runs with the smallest sample criterion allows.
+fn slow_days(c: &mut Criterion) {
+    bench_day::<day10::Day10>(c);
+}
+
+criterion_group!(benches, days);
+criterion_group! {
+    name = slow_benches;
+    config = Criterion::default().sample_size(10).measurement_time(Duration::from_secs(30));
+    targets = slow_days
+}
+criterion_main!(benches, slow_benches);
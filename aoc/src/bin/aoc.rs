@@ -0,0 +1,220 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc::ledger::{Entry, Ledger, Status};
+use aoc_core::{Answer, Day, DayResult, InputSource};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::{json, Value};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or only one part of it
+    Run {
+        day: u8,
+        part: Option<u8>,
+        /// Puzzle input file or `-` for stdin [default: $AOC_DAY<N>_INPUT, then the bundled input]
+        #[arg(short, long)]
+        input: Option<String>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Solve every implemented day and print a summary table
+    All {
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// List the implemented days and parts
+    List,
+    /// Re-solve every day and compare the answers with the ledger of confirmed answers
+    Verify {
+        /// Ledger of confirmed answers [default: answers.toml in the workspace]
+        #[arg(long)]
+        ledger: Option<PathBuf>,
+        /// Add new answers to the ledger as confirmed
+        #[arg(long)]
+        record: bool,
+    },
+}
+
+/// How answers are printed on stdout; diagnostics always go to stderr.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+enum Format {
+    /// A table of answers and timings
+    Text,
+    /// One JSON object per line for every day
+    Json,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run { day, part, input, format } => {
+            let Some(day) = aoc::day(day) else {
+                eprintln!("Day {} is not implemented", day);
+                return ExitCode::FAILURE;
+            };
+            let parts = match part {
+                Some(part) if !day.parts.contains(&part) => {
+                    eprintln!("Day {} has no part {}", day.day, part);
+                    return ExitCode::FAILURE;
+                }
+                Some(part) => vec![part],
+                None => day.parts.to_vec(),
+            };
+            let source = InputSource::resolve(day.day, input.as_deref());
+            solve_and_print(&[(day, parts, source)], format)
+        }
+        Command::All { format } => {
+            let days = aoc::days()
+                .into_iter()
+                .map(|day| (day, day.parts.to_vec(), InputSource::resolve(day.day, None)))
+                .collect::<Vec<_>>();
+            solve_and_print(&days, format)
+        }
+        Command::List => {
+            println!("{:>3}  parts", "day");
+            for day in aoc::days() {
+                let parts = day.parts.iter().map(|part| part.to_string()).collect::<Vec<_>>();
+                println!("{:>3}  {}", day.day, parts.join(", "));
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Verify { ledger, record } => verify(&ledger.unwrap_or_else(aoc::ledger::default_path), record),
+    }
+}
+
+fn solve_and_print(days: &[(Day, Vec<u8>, InputSource)], format: Format) -> ExitCode {
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut results = vec![];
+
+    for (day, parts, source) in days {
+        let solved = aoc_core::read(source)
+            .and_then(|file_content| day.solve(&file_content, parts).map_err(|err| err.in_file(source).to_string()));
+        if let Ok(result) = &solved {
+            for warning in &result.warnings {
+                eprintln!("Day {}: warning: {}", day.day, warning);
+            }
+        }
+        if let Err(err) = &solved {
+            eprintln!("Day {}: {}", day.day, err);
+            exit_code = ExitCode::FAILURE;
+        }
+        match format {
+            Format::Text => results.extend(solved.ok()),
+            Format::Json => println!("{}", json_object(day, source, &solved)),
+        }
+    }
+
+    if format == Format::Text {
+        print_table(&results);
+    }
+    exit_code
+}
+
+fn print_table(results: &[DayResult]) {
+    println!("{:>3}  {:>4}  {:>20}  {:>12}", "day", "part", "answer", "elapsed");
+    for result in results.iter().flat_map(|result| &result.parts) {
+        println!("{:>3}  {:>4}  {:>20}  {:>12}", result.day, result.part, result.answer.to_string(), format!("{:.3?}", result.elapsed));
+    }
+}
+
+/// The outcome of one day, as `{"day", "input", "parse_us", "parts": [{"part", "answer", "elapsed_us"}], "warnings", "error"}`.
+fn json_object(day: &Day, source: &InputSource, solved: &Result<DayResult, String>) -> Value {
+    match solved {
+        Ok(result) => {
+            let parts = result.parts.iter()
+                .map(|part| json!({
+                    "part": part.part,
+                    "answer": match part.answer {
+                        Answer::Unsigned(n) => json!(n),
+                        Answer::Signed(n) => json!(n),
+                    },
+                    "elapsed_us": part.elapsed.as_micros(),
+                }))
+                .collect::<Vec<_>>();
+            json!({
+                "day": day.day,
+                "input": source.to_string(),
+                "parse_us": result.parse_elapsed.as_micros(),
+                "parts": parts,
+                "warnings": result.warnings,
+                "error": null,
+            })
+        }
+        Err(err) => json!({
+            "day": day.day,
+            "input": source.to_string(),
+            "parse_us": null,
+            "parts": [],
+            "warnings": [],
+            "error": err,
+        }),
+    }
+}
+
+fn verify(ledger_path: &PathBuf, record: bool) -> ExitCode {
+    let mut ledger = match Ledger::load(ledger_path) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut checks = vec![];
+    for day in aoc::days() {
+        let source = InputSource::resolve(day.day, None);
+        let verified = aoc_core::read(&source)
+            .and_then(|file_content| aoc::ledger::verify(&ledger, &day, &file_content).map_err(|err| err.in_file(&source).to_string()));
+        match verified {
+            Ok(mut day_checks) => checks.append(&mut day_checks),
+            Err(err) => {
+                eprintln!("Day {}: {}", day.day, err);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    println!("{:>3}  {:>4}  {:<9}  answer", "day", "part", "status");
+    for check in &checks {
+        let (status, answer) = match &check.status {
+            Status::Confirmed => ("ok", String::new()),
+            Status::Mismatch { expected, actual } => ("MISMATCH", format!("{} (confirmed {})", actual, expected)),
+            Status::Missing { expected } => ("MISSING", format!("(confirmed {})", expected)),
+            Status::New { actual } => ("new", actual.clone()),
+        };
+        println!("{}", format!("{:>3}  {:>4}  {:<9}  {}", check.day, check.part, status, answer).trim_end());
+    }
+
+    let unchecked = ledger.entries.iter()
+        .filter(|entry| !checks.iter().any(|check| (check.day, check.part, &check.input) == (entry.day, entry.part, &entry.input)))
+        .count();
+    if unchecked > 0 {
+        println!("{} confirmed answers belong to inputs that are not available here", unchecked);
+    }
+
+    if checks.iter().any(|check| matches!(check.status, Status::Mismatch { .. } | Status::Missing { .. })) {
+        exit_code = ExitCode::FAILURE;
+    }
+
+    if record {
+        for check in checks {
+            if let Status::New { actual } = check.status {
+                ledger.record(Entry { day: check.day, part: check.part, input: check.input, answer: actual });
+            }
+        }
+        if let Err(err) = ledger.save(ledger_path) {
+            eprintln!("{}: {}", ledger_path.display(), err);
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    exit_code
+}
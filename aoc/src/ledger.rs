@@ -0,0 +1,128 @@
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+use aoc_core::{Day, ParseError};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// A confirmed answer for one part of one day, tied to the exact input it was computed from.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+/// The `answers.toml` file of previously confirmed answers.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default, rename = "answer")]
+    pub entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Loads the ledger at `path`; a missing file is an empty ledger.
+    pub fn load(path: &Path) -> Result<Ledger, String> {
+        match read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|err| format!("{}: {}", path.display(), err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(err) => Err(format!("{}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        write(path, toml::to_string(self).unwrap())
+    }
+
+    pub fn find(&self, day: u8, part: u8, input: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.day == day && entry.part == part && entry.input == input)
+    }
+
+    /// Adds `entry`, keeping the ledger ordered by day and part.
+    pub fn record(&mut self, entry: Entry) {
+        let pos = self.entries.partition_point(|e| (e.day, e.part) <= (entry.day, entry.part));
+        self.entries.insert(pos, entry);
+    }
+}
+
+pub fn default_path() -> PathBuf {
+    aoc_core::workspace_dir().join("answers.toml")
+}
+
+/// Identifies an input by the SHA-256 of its content, so answers of different inputs never mix.
+pub fn hash(file_content: &str) -> String {
+    Sha256::digest(file_content.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Status {
+    Confirmed,
+    Mismatch { expected: String, actual: String },
+    Missing { expected: String },
+    New { actual: String },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub status: Status,
+}
+
+/// Re-solves `file_content` with `day` and compares every part against the ledger.
+pub fn verify(ledger: &Ledger, day: &Day, file_content: &str) -> Result<Vec<Check>, ParseError> {
+    let input = hash(file_content);
+    let result = day.solve(file_content, &[1, 2])?;
+
+    let checks = [1, 2].into_iter()
+        .flat_map(|part| {
+            let expected = ledger.find(day.day, part, &input).map(|entry| entry.answer.clone());
+            let actual = result.answer(part).map(|answer| answer.to_string());
+            let status = match (expected, actual) {
+                (Some(expected), Some(actual)) if expected == actual => Some(Status::Confirmed),
+                (Some(expected), Some(actual)) => Some(Status::Mismatch { expected, actual }),
+                (Some(expected), None) => Some(Status::Missing { expected }),
+                (None, Some(actual)) => Some(Status::New { actual }),
+                (None, None) => None,
+            };
+            status.map(|status| Check { day: day.day, part, input: input.clone(), status })
+        })
+        .collect();
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let file_content = "0 3 6 9 12 15\n";
+        let input = hash(file_content);
+        let mut ledger = Ledger::default();
+        ledger.record(Entry { day: 9, part: 2, input: input.clone(), answer: "-3".to_string() });
+        ledger.record(Entry { day: 9, part: 1, input: input.clone(), answer: "17".to_string() });
+
+        let checks = verify(&ledger, &crate::day(9).unwrap(), file_content).unwrap();
+
+        assert_eq!(vec![1, 2], ledger.entries.iter().map(|e| e.part).collect::<Vec<_>>());
+        assert_eq!(Status::Mismatch { expected: "17".to_string(), actual: "18".to_string() }, checks[0].status);
+        assert_eq!(Status::Confirmed, checks[1].status);
+
+        let checks = verify(&Ledger::default(), &crate::day(9).unwrap(), file_content).unwrap();
+        assert_eq!(Status::New { actual: "18".to_string() }, checks[0].status);
+    }
+
+    #[test]
+    fn test_toml() {
+        let ledger = Ledger { entries: vec![Entry { day: 1, part: 2, input: hash(""), answer: "281".to_string() }] };
+
+        assert_eq!(ledger, toml::from_str(&toml::to_string(&ledger).unwrap()).unwrap());
+        assert_eq!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855", hash(""));
+    }
+}
@@ -0,0 +1,24 @@
+pub mod ledger;
+
+use aoc_core::Day;
+
+/// Every day that can be driven by the runner, in calendar order.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day1::Day1>(),
+        Day::of::<day2::Day2>(),
+        Day::of::<day3::Day3>(),
+        Day::of::<day4::Day4>(),
+        Day::of::<day5::Day5>(),
+        Day::of::<day6::Day6>(),
+        Day::of::<day7::Day7>(),
+        Day::of::<day8::Day8>(),
+        Day::of::<day9::Day9>(),
+        Day::of::<day10::Day10>(),
+        Day::of::<day11::Day11>(),
+    ]
+}
+
+pub fn day(day: u8) -> Option<Day> {
+    days().into_iter().find(|d| d.day == day)
+}
@@ -0,0 +1,66 @@
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+/// Every `data/example*.txt` of every day, each with a sibling `.expected` file of `part<N> = <answer>` lines.
+fn examples(data_dir: &Path) -> Vec<PathBuf> {
+    let mut examples = read_dir(data_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.starts_with("example") && name.ends_with(".txt")
+        })
+        .collect::<Vec<_>>();
+    examples.sort();
+    examples
+}
+
+fn parse_expected(content: &str) -> Vec<(u8, String)> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (key, value) = line.split_once('=').expect("Expected `part<N> = <answer>`");
+            let part = key.trim().strip_prefix("part").and_then(|n| n.parse::<u8>().ok()).expect("Expected `part<N>`");
+            (part, value.trim().to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn test_examples() {
+    let mut checked = 0;
+    let mut failures = vec![];
+
+    for day in aoc::days() {
+        let data_dir = aoc_core::default_path(day.day).parent().unwrap().to_path_buf();
+        let examples = examples(&data_dir);
+        assert!(!examples.is_empty(), "Day {} has no example input", day.day);
+
+        for example in examples {
+            let expected_path = example.with_extension("expected");
+            let expected = read_to_string(&expected_path)
+                .unwrap_or_else(|err| panic!("{}: {}", expected_path.display(), err));
+            let expected = parse_expected(&expected)
+                .into_iter()
+                .filter(|(part, _)| day.parts.contains(part))
+                .collect::<Vec<_>>();
+
+            let file_content = read_to_string(&example).unwrap();
+            let parts = expected.iter().map(|(part, _)| *part).collect::<Vec<_>>();
+            let result = day.solve(&file_content, &parts)
+                .unwrap_or_else(|err| panic!("{}", err.in_file(example.display())));
+
+            for (part, answer) in expected {
+                let actual = result.answer(part).map(|answer| answer.to_string());
+                if actual.as_deref() != Some(answer.as_str()) {
+                    failures.push(format!("{} part {}: expected {}, got {:?}", example.display(), part, answer, actual));
+                }
+                checked += 1;
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    assert!(checked > 0);
+}
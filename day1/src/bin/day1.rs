@@ -1,93 +1,85 @@
-use std::fs::read_to_string;
-use regex::{Regex};
-
-fn main() {
-    let result = read_to_string("day1/data/input.txt").map(parse_calibration_values);
-    match result {
-        Ok(calibration_values) => {
-            println!("{}", calibration_values.iter().sum::<u32>());
-        }
-        Err(err) => { println!("{}", err); }
-    }
+use std::io::IsTerminal;
+use std::process::ExitCode;
+use aoc_core::{InputArgs, Solution};
+use clap::{Parser, ValueEnum};
+use day1::explain::{explain, CSV_HEADER};
+use day1::{Day1, Mode, Scanner, Vocabulary};
+
+#[derive(Parser)]
+#[command(about = "Solves day 1 of Advent of Code 2023", long_about = None)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+    /// Spelled digits of part 2: `english`, `german`, `digits` or a file of `<word> = <digit>` lines
+    #[arg(long, default_value = "english")]
+    vocabulary: String,
+    /// Read part 2 as whole spelled numbers up to 999, like `twenty-one` or `one hundred and five`
+    #[arg(long, conflicts_with_all = ["vocabulary", "explain"])]
+    numbers: bool,
+    /// Skip lines without any digit instead of giving no answer, and report them on stderr
+    #[arg(long)]
+    lenient: bool,
+    /// Instead of the answers, explain the calibration value of every line
+    #[arg(long, value_enum)]
+    explain: Option<Explain>,
 }
 
-fn parse_calibration_values(file_content: String) -> Vec<u32>
-{
-    let lines = file_content.lines();
-    let from_front = Regex::new(r"(\d|one|two|three|four|five|six|seven|eight|nine)").unwrap();
-    let from_back = Regex::new(r"(\d|eno|owt|eerht|ruof|evif|xis|neves|thgie|enin)").unwrap();
-
-    let calibration_values = lines.into_iter()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-
-            let reversed_line = line.chars().rev().collect::<String>();
-            let maybe_first_match = from_front.find(line);
-            let maybe_last_match = from_back.find(reversed_line.as_str());
-
-            let result = maybe_first_match
-                .and_then(|first_match| {
-                    let first = to_digit(first_match.as_str());
-                    return maybe_last_match.map(|last_match| {
-                        let last = to_digit(last_match.as_str().chars().rev().collect::<String>().as_str());
-                        return first * 10 + last;
-                    });
-
-                });
-
-            println!("'{}' '{}' '{}' '{}'", line, maybe_first_match.map(|m| m.as_str()).unwrap_or_default(), maybe_last_match.map(|m| m.as_str()).unwrap_or_default(), result.unwrap_or_default());
-
-            return result.expect("Invalid line");
-        })
-        .collect();
-
-    return calibration_values;
-}
-
-
-fn to_digit(str: &str) -> u32 {
-    match str {
-        "one" => 1,
-        "two" => 2,
-        "three" => 3,
-        "four" => 4,
-        "five" => 5,
-        "six" => 6,
-        "seven" => 7,
-        "eight" => 8,
-        "nine" => 9,
-        _ => str.parse().expect("Not a number")
-    }
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Explain {
+    /// Lines with the matched words highlighted
+    Text,
+    /// One row per line with the spans, words and values
+    Csv,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let input = "two1nine
-            eightwothree
-            abcone2threexyz
-            xtwone3four
-            4nineeightseven2
-            zoneight234
-            7pqrstsixteen".to_string();
-
-        let result = parse_calibration_values(input);
-
-        assert_eq!(vec![29, 83, 13, 24, 42, 14, 76], result);
-        assert_eq!(281, result.iter().sum::<u32>());
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let source = args.input.source(Day1::DAY);
+    let loaded = args.input.parts::<Day1>()
+        .and_then(|parts| Vocabulary::load(&args.vocabulary).map(|vocabulary| (parts, vocabulary)))
+        .and_then(|(parts, vocabulary)| {
+            let file_content = aoc_core::read(&source)?;
+            Ok((parts, vocabulary, file_content))
+        });
+    let (parts, vocabulary, file_content) = match loaded {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(format) = args.explain {
+        // Part 1 only knows digits, so explaining it alone uses them instead of the vocabulary.
+        let scanner = Scanner::new(if parts == [1] { Vocabulary::digits() } else { vocabulary });
+        let explanations = explain(&file_content, &scanner);
+        match format {
+            Explain::Text => {
+                let colored = std::io::stdout().is_terminal();
+                explanations.iter().for_each(|e| println!("{}", e.highlighted(&scanner, colored)));
+            }
+            Explain::Csv => {
+                println!("{}", CSV_HEADER);
+                explanations.iter().for_each(|e| println!("{}", e.csv_row(&scanner)));
+            }
+        }
+        return ExitCode::SUCCESS;
     }
 
-    #[test]
-    fn test_edge() {
-        let input = "s8twoned".to_string();
-
-        let result = parse_calibration_values(input);
-
-        assert_eq!(vec![81], result);
+    let mode = if args.lenient { Mode::Lenient } else { Mode::Strict };
+    let parsed = if args.numbers {
+        Day1::parse_numbers(&file_content, mode)
+    } else {
+        Day1::parse_with(&file_content, vocabulary, mode)
+    };
+    match parsed {
+        Ok(document) => {
+            aoc_core::print_answers::<Day1>(&document, &parts);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err.in_file(&source));
+            ExitCode::FAILURE
+        }
     }
-
-
-}
\ No newline at end of file
+}
@@ -0,0 +1,113 @@
+use termion::{color, style};
+use crate::{CalibrationScanner, Scanner, WordMatch};
+
+/// How the calibration value of one line came about.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Explanation<'a> {
+    /// 1-based, counting empty lines as well.
+    pub line_number: usize,
+    pub line: &'a str,
+    pub first: Option<WordMatch>,
+    pub last: Option<WordMatch>,
+    pub value: Option<u64>,
+}
+
+pub const CSV_HEADER: &str = "line,text,first_start,first_end,first_word,first_value,last_start,last_end,last_word,last_value,value";
+
+/// Explains every non-empty line of `file_content`, including those without a calibration value.
+pub fn explain<'a>(file_content: &'a str, scanner: &Scanner) -> Vec<Explanation<'a>> {
+    file_content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            let matches = scanner.first_and_last(line);
+            Explanation {
+                line_number: idx + 1,
+                line,
+                first: matches.map(|(first, _)| first),
+                last: matches.map(|(_, last)| last),
+                value: scanner.calibration_value(line),
+            }
+        })
+        .collect()
+}
+
+impl Explanation<'_> {
+    /// The line with the first word in green, the last one in red and letters they share in yellow,
+    /// followed by the matched words with their byte spans and the value.
+    ///
+    /// Without `colored`, for output that is not a terminal, the line is left as it is.
+    pub fn highlighted(&self, scanner: &Scanner, colored: bool) -> String {
+        let describe = |word: Option<WordMatch>| word.map_or("-".to_string(), |w| {
+            format!("{:?} {}..{}", scanner.vocabulary().entry(w.entry).0, w.start, w.end)
+        });
+        let description = format!("first {}, last {}, value {}",
+                                  describe(self.first), describe(self.last),
+                                  self.value.map_or("-".to_string(), |v| v.to_string()));
+        if !colored {
+            return format!("{:>5}  {}  {}", self.line_number, self.line, description);
+        }
+
+        let within = |word: Option<WordMatch>, idx: usize| word.is_some_and(|w| w.start <= idx && idx < w.end);
+        let mut text = String::new();
+        let mut current = String::new();
+        for (idx, c) in self.line.char_indices() {
+            let highlight = match (within(self.first, idx), within(self.last, idx)) {
+                (true, true) => color::Fg(color::Yellow).to_string(),
+                (true, false) => color::Fg(color::Green).to_string(),
+                (false, true) => color::Fg(color::Red).to_string(),
+                (false, false) => style::Reset.to_string(),
+            };
+            if highlight != current {
+                text.push_str(&highlight);
+                current = highlight;
+            }
+            text.push(c);
+        }
+
+        format!("{:>5}  {}{}  {}", self.line_number, text, style::Reset, description)
+    }
+
+    pub fn csv_row(&self, scanner: &Scanner) -> String {
+        let word_fields = |word: Option<WordMatch>| match word {
+            Some(w) => {
+                let (entry, value) = scanner.vocabulary().entry(w.entry);
+                vec![w.start.to_string(), w.end.to_string(), csv_field(entry), value.to_string()]
+            }
+            None => vec![String::new(); 4],
+        };
+
+        let mut fields = vec![self.line_number.to_string(), csv_field(self.line)];
+        fields.extend(word_fields(self.first));
+        fields.extend(word_fields(self.last));
+        fields.push(self.value.map(|v| v.to_string()).unwrap_or_default());
+        fields.join(",")
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vocabulary;
+
+    #[test]
+    fn test_explain() {
+        let scanner = Scanner::new(Vocabulary::english());
+        let explanations = explain("zoneight234\n\nno, \"x\"\n", &scanner);
+
+        assert_eq!(2, explanations.len());
+        assert_eq!("1,zoneight234,1,4,one,1,10,11,4,4,14", explanations[0].csv_row(&scanner));
+        assert_eq!("3,\"no, \"\"x\"\"\",,,,,,,,,", explanations[1].csv_row(&scanner));
+        assert!(explanations[0].highlighted(&scanner, true).contains("first \"one\" 1..4, last \"4\" 10..11, value 14"));
+        assert_eq!("    1  zoneight234  first \"one\" 1..4, last \"4\" 10..11, value 14", explanations[0].highlighted(&scanner, false));
+    }
+}
@@ -0,0 +1,385 @@
+//! Day 1: Trebuchet?!
+
+use std::fs::read_to_string;
+use std::path::Path;
+use aho_corasick::{AhoCorasick, AhoCorasickKind};
+use aoc_core::{parse_lines, parse_number, Answer, ParseError, Solution, Solved};
+use crate::numbers::NumberScanner;
+
+pub mod explain;
+pub mod numbers;
+
+/// The words a calibration value can be made of, each with the digit it stands for.
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// Only the digits `0` to `9`, as in part 1.
+    pub fn digits() -> Vocabulary {
+        let words = (0..10).map(|n| (n.to_string(), n)).collect();
+        return Vocabulary { words };
+    }
+
+    /// The digits and the English words `one` to `nine`, as in part 2.
+    pub fn english() -> Vocabulary {
+        Vocabulary::spelled(&["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"])
+    }
+
+    /// The digits and the German words `eins` to `neun`.
+    pub fn german() -> Vocabulary {
+        Vocabulary::spelled(&["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"])
+    }
+
+    /// The digits and the names of `1` to `9`, in that order.
+    fn spelled(names: &[&str]) -> Vocabulary {
+        Vocabulary::with_words(names.iter().zip(1..).map(|(name, n)| (name.to_string(), n)))
+    }
+
+    /// The digits and `words`.
+    pub fn with_words(words: impl IntoIterator<Item = (String, u32)>) -> Vocabulary {
+        let mut vocabulary = Vocabulary::digits();
+        vocabulary.words.extend(words);
+        return vocabulary;
+    }
+
+    /// A built-in vocabulary: `digits`, `english` or `german`.
+    pub fn by_name(name: &str) -> Option<Vocabulary> {
+        match name {
+            "digits" => Some(Vocabulary::digits()),
+            "english" => Some(Vocabulary::english()),
+            "german" => Some(Vocabulary::german()),
+            _ => None
+        }
+    }
+
+    /// The digits and the words of a vocabulary file, one `<word> = <digit>` per line.
+    pub fn parse(text: &str) -> Result<Vocabulary, ParseError> {
+        let words = parse_lines(text, |line| {
+            let (word, value) = line.split_once('=').ok_or_else(|| ParseError::at_end(line, "`<word> = <digit>`"))?;
+            let word = word.trim();
+            if word.is_empty() {
+                return Err(ParseError::at(line, line, "a word"));
+            }
+            let value = value.trim();
+            let value = parse_number::<u32>(line, value).ok().filter(|n| *n < 10)
+                .ok_or_else(|| ParseError::at(line, value, "a digit"))?;
+            return Ok((word.to_string(), value));
+        })?;
+        return Ok(Vocabulary::with_words(words));
+    }
+
+    /// A built-in vocabulary by name, or else the vocabulary file at `name_or_path`.
+    pub fn load(name_or_path: &str) -> Result<Vocabulary, String> {
+        if let Some(vocabulary) = Vocabulary::by_name(name_or_path) {
+            return Ok(vocabulary);
+        }
+        let path = Path::new(name_or_path);
+        let text = read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        return Vocabulary::parse(&text).map_err(|err| err.in_file(path.display()).to_string());
+    }
+
+    /// The word at `entry` and the digit it stands for.
+    pub fn entry(&self, entry: usize) -> (&str, u32) {
+        let (word, value) = &self.words[entry];
+        (word, *value)
+    }
+}
+
+/// Finds the calibration value of a line.
+pub trait CalibrationScanner {
+    fn calibration_value(&self, line: &str) -> Option<u64>;
+}
+
+/// A vocabulary word found in a line, at the bytes `start..end`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct WordMatch {
+    pub start: usize,
+    pub end: usize,
+    /// The index of the matched word in its vocabulary.
+    pub entry: usize,
+    pub value: u32,
+}
+
+/// Finds the first and the last word of a vocabulary in a line.
+///
+/// An Aho-Corasick automaton reports every match, overlapping ones included, in a single pass over
+/// the line, so words sharing letters like the `oneight` in `zoneight234` count as both `one` and
+/// `eight`. Where words start (or for the last word, end) at the same place, the longest one wins.
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    vocabulary: Vocabulary,
+    automaton: AhoCorasick,
+}
+
+impl Scanner {
+    pub fn new(vocabulary: Vocabulary) -> Scanner {
+        let automaton = AhoCorasick::builder()
+            .kind(Some(AhoCorasickKind::DFA))
+            .build(vocabulary.words.iter().map(|(w, _)| w))
+            .unwrap();
+        return Scanner { vocabulary, automaton };
+    }
+
+    pub fn vocabulary(&self) -> &Vocabulary {
+        &self.vocabulary
+    }
+
+    /// The first and the last word in `line`, if it contains one at all.
+    pub fn first_and_last(&self, line: &str) -> Option<(WordMatch, WordMatch)> {
+        let mut first: Option<WordMatch> = None;
+        let mut last: Option<WordMatch> = None;
+
+        for m in self.automaton.find_overlapping_iter(line) {
+            let entry = m.pattern().as_usize();
+            let word = WordMatch { start: m.start(), end: m.end(), entry, value: self.vocabulary.words[entry].1 };
+            if first.is_none_or(|f| (word.start, f.end) < (f.start, word.end)) {
+                first = Some(word);
+            }
+            if last.is_none_or(|l| (l.end, word.start) < (word.end, l.start)) {
+                last = Some(word);
+            }
+        }
+
+        return first.zip(last);
+    }
+
+}
+
+impl CalibrationScanner for Scanner {
+    /// The first and the last digit of `line` as a two-digit number, if it contains a word at all.
+    fn calibration_value(&self, line: &str) -> Option<u64> {
+        let (first, last) = self.first_and_last(line)?;
+        return Some(u64::from(first.value * 10 + last.value));
+    }
+}
+
+/// What to do with lines that have no calibration value.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Mode {
+    /// Give no answer for the part, naming the first such line.
+    Strict,
+    /// Skip them and sum the rest.
+    Lenient,
+}
+
+/// The scanner part 2 reads the document with.
+#[derive(Debug, Clone)]
+pub enum Part2Scanner {
+    /// The words of a vocabulary, each standing for a digit.
+    Words(Scanner),
+    /// Whole spelled numbers like `twenty-one`.
+    Numbers(NumberScanner),
+}
+
+impl CalibrationScanner for Part2Scanner {
+    fn calibration_value(&self, line: &str) -> Option<u64> {
+        match self {
+            Part2Scanner::Words(scanner) => scanner.calibration_value(line),
+            Part2Scanner::Numbers(scanner) => scanner.calibration_value(line),
+        }
+    }
+}
+
+/// A document together with the scanners that calibrate its two parts, each built once.
+#[derive(Debug, Clone)]
+pub struct Document<'a> {
+    text: &'a str,
+    digits: Scanner,
+    scanner: Part2Scanner,
+    mode: Mode,
+}
+
+impl Document<'_> {
+    /// Calibrates part 1, which only knows digits, or else part 2, which reads the words of its
+    /// scanner, by reading every line.
+    pub fn calibrate(&self, part: u8) -> Result<Calibration, ParseError> {
+        match part {
+            1 => calibrate(self.text, &self.digits, self.mode),
+            _ => calibrate(self.text, &self.scanner, self.mode),
+        }
+    }
+}
+
+/// The sum of the calibration values of the lines that have one, and the line numbers of those that do not.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Calibration {
+    pub sum: u64,
+    pub skipped: Vec<usize>,
+}
+
+impl Calibration {
+    /// Adds the calibration `value` of the line at `idx`; a line without one is skipped in lenient mode.
+    fn add(&mut self, idx: usize, line: &str, value: Option<u64>, mode: Mode) -> Result<(), ParseError> {
+        match (value, mode) {
+            (Some(value), _) => self.sum += value,
+            (None, Mode::Lenient) => self.skipped.push(idx + 1),
+            (None, Mode::Strict) => return Err(ParseError::at(line, line, "a digit").offset_lines(idx)),
+        }
+        return Ok(());
+    }
+
+    /// A summary of the skipped lines, listing at most the first ten.
+    pub fn skipped_summary(&self) -> Option<String> {
+        let (count, listed) = (self.skipped.len(), self.skipped.len().min(10));
+        if count == 0 {
+            return None;
+        }
+        let lines = self.skipped[..listed].iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ");
+        let more = if count > listed { format!(" and {} more", count - listed) } else { String::new() };
+        return Some(format!("skipped {} line{} without a digit: {}{}", count, if count == 1 { "" } else { "s" }, lines, more));
+    }
+}
+
+pub struct Day1;
+
+impl Day1 {
+    /// Reads a document whose digits are spelled in `vocabulary`.
+    ///
+    /// In strict mode, a part has no answer if a line has no digit; in lenient mode, the line is skipped.
+    pub fn parse_with(file_content: &str, vocabulary: Vocabulary, mode: Mode) -> Result<Document<'_>, ParseError> {
+        Ok(Day1::document(file_content, Part2Scanner::Words(Scanner::new(vocabulary)), mode))
+    }
+
+    /// Reads a document whose part 2 is made of whole spelled numbers, see [`NumberScanner`].
+    pub fn parse_numbers(file_content: &str, mode: Mode) -> Result<Document<'_>, ParseError> {
+        Ok(Day1::document(file_content, Part2Scanner::Numbers(NumberScanner::new()), mode))
+    }
+
+    fn document(file_content: &str, scanner: Part2Scanner, mode: Mode) -> Document<'_> {
+        Document { text: file_content, digits: Scanner::new(Vocabulary::digits()), scanner, mode }
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Document<'a>;
+
+    fn parse(file_content: &str) -> Result<Document<'_>, ParseError> {
+        Day1::parse_with(file_content, Vocabulary::english(), Mode::Strict)
+    }
+
+    fn part1(document: &Document) -> Option<Answer> {
+        Some(document.calibrate(1).ok()?.sum.into())
+    }
+
+    fn part2(document: &Document) -> Option<Answer> {
+        Some(document.calibrate(2).ok()?.sum.into())
+    }
+
+    fn solve_part(document: &Document, part: u8) -> Solved {
+        if !Day1::PARTS.contains(&part) {
+            return Solved::default();
+        }
+        return match document.calibrate(part) {
+            Ok(calibration) => Solved {
+                answer: Some(calibration.sum.into()),
+                warnings: calibration.skipped_summary().map(|summary| format!("part {} {}", part, summary)).into_iter().collect(),
+            },
+            Err(err) => Solved {
+                answer: None,
+                warnings: vec![format!("part {} has no answer, line {} has no digit", part, err.line)],
+            },
+        };
+    }
+}
+
+/// Sums the calibration values of every line, made of the words `scanner` looks for.
+///
+/// In strict mode, a line without any digit is an error; in lenient mode, it is skipped.
+fn calibrate(file_content: &str, scanner: &dyn CalibrationScanner, mode: Mode) -> Result<Calibration, ParseError>
+{
+    let mut calibration = Calibration::default();
+    for (idx, line) in file_content.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+        calibration.add(idx, line, scanner.calibration_value(line), mode)?;
+    }
+    return Ok(calibration);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let input = "two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen".to_string();
+
+        let scanner = Scanner::new(Vocabulary::english());
+        let values = input.lines().map(|line| scanner.calibration_value(line).unwrap()).collect::<Vec<_>>();
+
+        assert_eq!(vec![29, 83, 13, 24, 42, 14, 76], values);
+        assert_eq!(281, calibrate(&input, &scanner, Mode::Strict).unwrap().sum);
+    }
+
+    #[test]
+    fn test_digits() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\nseven";
+        let scanner = Scanner::new(Vocabulary::digits());
+
+        assert_eq!(Some(12), scanner.calibration_value("1abc2"));
+        assert_eq!(Some(77), scanner.calibration_value("treb7uchet"));
+        assert_eq!(Some(22), scanner.calibration_value("two2three"));
+        assert_eq!(5, calibrate(input, &scanner, Mode::Strict).unwrap_err().line);
+        assert_eq!(vec![5], calibrate(input, &scanner, Mode::Lenient).unwrap().skipped);
+    }
+
+    #[test]
+    fn test_lenient() {
+        let input = "two1nine\nnothing\n\n7pqrstsixteen\nxyz\neight";
+        let document = Day1::parse_with(input, Vocabulary::english(), Mode::Lenient).unwrap();
+        let strict = Day1::parse(input).unwrap();
+
+        assert_eq!(None, Day1::part2(&strict));
+        assert_eq!(vec!["part 2 has no answer, line 2 has no digit"], Day1::solve_part(&strict, 2).warnings);
+        assert_eq!(Some(Answer::from(193u32)), Day1::part2(&document));
+        assert_eq!(Some(Answer::from(88u32)), Day1::part1(&document));
+        assert_eq!(vec!["part 1 skipped 3 lines without a digit: 2, 5, 6"], Day1::solve_part(&document, 1).warnings);
+        assert_eq!(vec!["part 2 skipped 2 lines without a digit: 2, 5"], Day1::solve_part(&document, 2).warnings);
+    }
+
+    #[test]
+    fn test_edge() {
+        let input = "s8twoned".to_string();
+
+        let result = calibrate(&input, &Scanner::new(Vocabulary::english()), Mode::Strict).unwrap();
+
+        assert_eq!(81, result.sum);
+    }
+
+    #[test]
+    fn test_large_sum() {
+        struct Largest;
+        impl CalibrationScanner for Largest {
+            fn calibration_value(&self, _line: &str) -> Option<u64> {
+                Some(u64::from(u32::MAX))
+            }
+        }
+
+        assert_eq!(2 * u64::from(u32::MAX), calibrate("a\nb\n", &Largest, Mode::Strict).unwrap().sum);
+    }
+
+    #[test]
+    fn test_vocabularies() {
+        let german = Scanner::new(Vocabulary::german());
+        let english = Scanner::new(Vocabulary::english());
+        let french = Scanner::new(Vocabulary::parse("un = 1\ndeux = 2\ntrois = 3\n\nhuit = 8").unwrap());
+
+        assert_eq!(Some(21), german.calibration_value("xzweinsy"));
+        assert_eq!(Some(58), german.calibration_value("fünfsechsiebenacht"));
+        assert_eq!(Some(18), english.calibration_value("oneight"));
+        assert_eq!(Some((WordMatch { start: 1, end: 4, entry: 10, value: 1 }, WordMatch { start: 3, end: 8, entry: 17, value: 8 })), english.first_and_last("zoneight"));
+        assert_eq!(Some(83), french.calibration_value("huitrois"));
+        assert_eq!(None, french.calibration_value("one"));
+        assert_eq!(2, Vocabulary::parse("un = 1\ndeux = x").unwrap_err().line);
+        assert!(Vocabulary::parse("un = 12").is_err());
+    }
+
+}
@@ -0,0 +1,181 @@
+use aho_corasick::{AhoCorasick, AhoCorasickKind};
+use crate::CalibrationScanner;
+
+const UNITS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+const TEENS: [&str; 10] = ["ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen"];
+const TENS: [&str; 8] = ["twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+/// How far a spelled number has been read, with the value read so far.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Stage {
+    Start,
+    Unit(u32),
+    Tens(u32),
+    Hundred(u32),
+    And(u32),
+    Done(u32),
+}
+
+/// Reads the next word of a spelled number, or `None` if the number cannot continue with it.
+fn step(stage: Stage, word: &str) -> Option<Stage> {
+    let unit = UNITS.iter().position(|w| *w == word).map(|idx| idx as u32 + 1);
+    let teen = TEENS.iter().position(|w| *w == word).map(|idx| idx as u32 + 10);
+    let tens = TENS.iter().position(|w| *w == word).map(|idx| idx as u32 * 10 + 20);
+
+    match (stage, unit, teen, tens, word) {
+        (Stage::Start, Some(u), _, _, _) => Some(Stage::Unit(u)),
+        (Stage::Start, _, Some(t), _, _) => Some(Stage::Done(t)),
+        (Stage::Start, _, _, Some(t), _) => Some(Stage::Tens(t)),
+        (Stage::Unit(u), _, _, _, "hundred") => Some(Stage::Hundred(u * 100)),
+        (Stage::Tens(v), Some(u), _, _, _) => Some(Stage::Done(v + u)),
+        (Stage::Hundred(v), _, _, _, "and") => Some(Stage::And(v)),
+        (Stage::Hundred(v) | Stage::And(v), Some(u), _, _, _) => Some(Stage::Done(v + u)),
+        (Stage::Hundred(v) | Stage::And(v), _, Some(t), _, _) => Some(Stage::Done(v + t)),
+        (Stage::Hundred(v) | Stage::And(v), _, _, Some(t), _) => Some(Stage::Tens(v + t)),
+        _ => None
+    }
+}
+
+fn value(stage: Stage) -> Option<u32> {
+    match stage {
+        Stage::Unit(v) | Stage::Tens(v) | Stage::Hundred(v) | Stage::Done(v) => Some(v),
+        Stage::Start | Stage::And(_) => None,
+    }
+}
+
+/// The value of a spelled number from `one` to `nine hundred and ninety-nine`, given as its words,
+/// like `["nine", "hundred", "and", "ninety", "nine"]`.
+pub fn parse_number_words(words: &[&str]) -> Option<u32> {
+    let stage = words.iter().try_fold(Stage::Start, |stage, word| step(stage, word))?;
+    return value(stage);
+}
+
+/// A number found in a line, at the bytes `start..end`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NumberMatch {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+/// Finds the first and the last number in a line, where numbers are single digits or English
+/// numbers up to 999 spelled in one or more words.
+///
+/// The words of a number may follow each other directly or be separated by a space or a hyphen, as
+/// in `twentyone`, `twenty-one` or `one hundred and five`. The longest number wins, so `eighteen`
+/// is 18 rather than 8.
+#[derive(Debug, Clone)]
+pub struct NumberScanner {
+    words: Vec<&'static str>,
+    automaton: AhoCorasick,
+}
+
+impl NumberScanner {
+    pub fn new() -> NumberScanner {
+        let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        let words = digits.iter().chain(&UNITS).chain(&TEENS).chain(&TENS).chain(&["hundred", "and"]).copied().collect::<Vec<_>>();
+        let automaton = AhoCorasick::builder()
+            .kind(Some(AhoCorasickKind::DFA))
+            .build(&words)
+            .unwrap();
+        return NumberScanner { words, automaton };
+    }
+
+    /// Every number in `line`, overlapping ones included.
+    pub fn numbers(&self, line: &str) -> Vec<NumberMatch> {
+        let mut tokens = self.automaton.find_overlapping_iter(line)
+            .map(|m| (m.start(), m.end(), self.words[m.pattern().as_usize()]))
+            .collect::<Vec<_>>();
+        tokens.sort_unstable();
+        let starting_at = |start: usize| {
+            let from = tokens.partition_point(|(s, _, _)| *s < start);
+            let to = tokens.partition_point(|(s, _, _)| *s <= start);
+            return &tokens[from..to];
+        };
+
+        let mut numbers = vec![];
+        let mut pending = vec![];
+        for &(start, end, word) in &tokens {
+            if let Some(digit) = word.parse::<u32>().ok().filter(|_| word.len() == 1) {
+                numbers.push(NumberMatch { start, end, value: digit });
+                continue;
+            }
+            pending.push((end, Stage::Start, word));
+            while let Some((end, stage, word)) = pending.pop() {
+                let Some(stage) = step(stage, word) else { continue };
+                if let Some(value) = value(stage) {
+                    numbers.push(NumberMatch { start, end, value });
+                }
+                let separated = line[end..].starts_with([' ', '-']);
+                for next in [Some(end), separated.then_some(end + 1)].into_iter().flatten() {
+                    pending.extend(starting_at(next).iter().map(|&(_, e, w)| (e, stage, w)));
+                }
+            }
+        }
+        return numbers;
+    }
+
+    /// The first and the last number in `line`, if it contains one at all.
+    pub fn first_and_last(&self, line: &str) -> Option<(NumberMatch, NumberMatch)> {
+        let numbers = self.numbers(line);
+        let first = numbers.iter().min_by_key(|n| (n.start, std::cmp::Reverse(n.end)))?;
+        let last = numbers.iter().max_by_key(|n| (n.end, std::cmp::Reverse(n.start)))?;
+        return Some((*first, *last));
+    }
+}
+
+impl Default for NumberScanner {
+    fn default() -> Self {
+        NumberScanner::new()
+    }
+}
+
+impl CalibrationScanner for NumberScanner {
+    /// The digits of the first number followed by those of the last one, such as 21105 for
+    /// `twentyone...one hundred and five`.
+    fn calibration_value(&self, line: &str) -> Option<u64> {
+        let (first, last) = self.first_and_last(line)?;
+        return Some(u64::from(first.value) * 10u64.pow(last.value.max(1).ilog10() + 1) + u64::from(last.value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Answer, Solution};
+    use crate::{Day1, Mode};
+
+    #[test]
+    fn test_parse_number_words() {
+        assert_eq!(Some(7), parse_number_words(&["seven"]));
+        assert_eq!(Some(14), parse_number_words(&["fourteen"]));
+        assert_eq!(Some(21), parse_number_words(&["twenty", "one"]));
+        assert_eq!(Some(100), parse_number_words(&["one", "hundred"]));
+        assert_eq!(Some(999), parse_number_words(&["nine", "hundred", "and", "ninety", "nine"]));
+        assert_eq!(Some(512), parse_number_words(&["five", "hundred", "twelve"]));
+        assert_eq!(None, parse_number_words(&["one", "hundred", "and"]));
+        assert_eq!(None, parse_number_words(&["twelve", "one"]));
+        assert_eq!(None, parse_number_words(&["hundred"]));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let scanner = NumberScanner::new();
+
+        assert_eq!(Some(21105), scanner.calibration_value("xtwentyone3...one hundred and five"));
+        assert_eq!(Some(9918), scanner.calibration_value("ninety-nine eighteen"));
+        assert_eq!(Some(18), scanner.calibration_value("oneight"));
+        assert_eq!(Some(1313), scanner.calibration_value("thirteen"));
+        assert_eq!(Some(4), scanner.calibration_value("04"));
+        assert_eq!(None, scanner.calibration_value("hundred and"));
+        assert_eq!(Some(999999), scanner.calibration_value("nine hundred and ninety-nine"));
+
+        let input = "nine hundred and ninety-nine\n".repeat(5000);
+        let document = Day1::parse_numbers(&input, Mode::Strict).unwrap();
+        assert_eq!(Some(Answer::from(4_999_995_000u64)), Day1::part2(&document));
+
+        let (first, last) = scanner.first_and_last("a twenty-one b fifteen").unwrap();
+        assert_eq!((2, 12, 21), (first.start, first.end, first.value));
+        assert_eq!((15, 22, 15), (last.start, last.end, last.value));
+    }
+}
@@ -0,0 +1,281 @@
+//! Day 10: Pipe Maze
+
+use std::collections::{HashSet, LinkedList};
+use std::iter::once;
+use aoc_core::{Answer, Grid, Offset, ParseError, Pos, Solution};
+use itertools::{Itertools, unfold};
+use termion::{color, style};
+use termion::color::{Green, Red};
+
+/// The cells each cell's pipe connects to, keeping only connections that lead both ways.
+pub type Pipes = Grid<Vec<Pos>>;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Joint {
+    nw: Option<Pos>,
+    ne: Option<Pos>,
+    sw: Option<Pos>,
+    se: Option<Pos>,
+}
+
+impl Joint {
+    fn new(nw: Option<Pos>, ne: Option<Pos>, sw: Option<Pos>, se: Option<Pos>) -> Joint {
+        Joint { nw, ne, sw, se }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = (Pos, Pipes);
+
+    fn parse(file_content: &str) -> Result<(Pos, Pipes), ParseError> {
+        let tiles = parse_input(file_content)?;
+        let start = tiles.position(|c| *c == 'S')
+            .ok_or_else(|| ParseError::at_end(file_content, "a start tile `S`"))?;
+        let bidirectional = filter_bidirectional(&connections(&tiles));
+        Ok((start, bidirectional))
+    }
+
+    fn part1((start, bidirectional): &(Pos, Pipes)) -> Option<Answer> {
+        let loops = reachable_loops(bidirectional, start);
+        let path = loops.first()?;
+
+        let last = path.back()?;
+        let loop_start = path.iter().find_position(|p| *p == last).unwrap().0;
+        let farthest = (path.len() - loop_start) / 2 + loop_start;
+        Some(farthest.into())
+    }
+
+    fn part2((start, bidirectional): &(Pos, Pipes)) -> Option<Answer> {
+        let (loop_points, flooded_points) = loop_and_flooded_points(bidirectional, start)?;
+
+        let mut enclosed = bidirectional.positions().collect::<HashSet<_>>();
+        loop_points.iter().for_each(|p| { enclosed.remove(p); });
+        flooded_points.iter().for_each(|p| { enclosed.remove(p); });
+        Some(enclosed.len().into())
+    }
+}
+
+/// The cells of the loop through `start`, and the cells reachable from the outside by squeezing
+/// between pipes, or `None` if no loop goes through `start`.
+pub fn loop_and_flooded_points(map: &Pipes, start: &Pos) -> Option<(HashSet<Pos>, HashSet<Pos>)> {
+    let loops = reachable_loops(map, start);
+    let path = loops.first()?;
+    let loop_points = path.iter().cloned().collect::<HashSet<_>>();
+
+    let flooded_joints = fill(map);
+    let flooded_points = flooded_joints.iter().flat_map(|j| [j.nw, j.ne, j.sw, j.se]).flatten().collect::<HashSet<_>>();
+
+    Some((loop_points, flooded_points))
+}
+
+/// Draws the pipes, if there is a loop: in color, with the loop in red and the cells outside of
+/// it in green, and otherwise with only the loop's pipes and an `I` on every cell it encloses.
+pub fn render_map((start, bidirectional): &(Pos, Pipes), colored: bool) -> Option<String> {
+    let (loop_points, flooded_points) = loop_and_flooded_points(bidirectional, start)?;
+    let mut text = String::new();
+    for row in 0..bidirectional.height() {
+        for col in 0..bidirectional.width() {
+            let pos = Pos::new(row, col);
+            let pipe = pipe(bidirectional, pos);
+            let cell = match (colored, loop_points.contains(&pos), flooded_points.contains(&pos)) {
+                (true, true, _) => format!("{}{}", color::Bg(Red), pipe),
+                (true, false, true) => format!("{}{}", color::Bg(Green), pipe),
+                (true, false, false) => format!("{}{}", style::Reset, pipe),
+                (false, true, _) => pipe.to_string(),
+                (false, false, true) => " ".to_string(),
+                (false, false, false) => "I".to_string(),
+            };
+            text.push_str(&cell);
+        }
+        if colored {
+            text.push_str(style::Reset.as_ref());
+        }
+        text.push('\n');
+    }
+    Some(text)
+}
+
+/// The box-drawing character of the pipe at `pos`, or a space if it connects to fewer than two cells.
+fn pipe(map: &Pipes, pos: Pos) -> char {
+    let edges = &map[pos];
+    let connected = |offset: Offset| map.step(pos, offset).is_some_and(|n| edges.contains(&n));
+    let (north, south, west, east) = (connected(Offset::NORTH), connected(Offset::SOUTH), connected(Offset::WEST), connected(Offset::EAST));
+    if north && south { '┃' } else if east && west { '━' } else if north && east { '┗' } else if north && west { '┛' } else if south && west { '┓' } else if south && east { '┏' } else { ' ' }
+}
+
+/// The paths from `start` that end up back on themselves.
+pub fn reachable_loops(map: &Pipes, start: &Pos) -> Vec<LinkedList<Pos>> {
+    let start_path = once(*start).collect::<LinkedList<_>>();
+    let loops = unfold(vec![start_path], |paths| {
+        if paths.is_empty() {
+            return None;
+        }
+
+        let (new_loops, mut new_paths): (Vec<_>, Vec<_>) =
+            paths
+                .drain(..)
+                .flat_map(|path| next_paths(map, path))
+                .partition(is_loop);
+
+        paths.append(&mut new_paths);
+
+        Some(new_loops)
+    }).flatten().collect_vec();
+
+    return loops;
+}
+
+fn fill(map: &Pipes) -> HashSet<Joint> {
+    let initial_visited = HashSet::<Joint>::new();
+    let start = Joint::new(Some(Pos::new(0, 0)), Some(Pos::new(0, 1)), Some(Pos::new(1, 0)), Some(Pos::new(1, 1)));
+    let initial_current = once(start).collect::<HashSet<_>>();
+
+    let joints = unfold((initial_visited, initial_current), |(visited, current)| {
+        if current.is_empty() {
+            return None;
+        }
+
+        let mut next = current.iter()
+            .flat_map(|c| reachable(map, c))
+            .filter(|j| !(visited.contains(j) || current.contains(j)))
+            .collect::<HashSet<_>>();
+
+        let result = current.iter().cloned().collect::<HashSet<_>>();
+
+        current.drain().for_each(|j| { visited.insert(j); });
+        next.drain().for_each(|j| { current.insert(j); });
+
+        Some(result)
+    })
+        .flatten()
+        .collect::<HashSet<Joint>>();
+
+    joints
+}
+
+fn reachable(map: &Pipes, current: &Joint) -> Vec<Joint> {
+    let nnw = current.nw.and_then(|nw| map.step(nw, Offset::NORTH));
+    let nne = current.ne.and_then(|ne| map.step(ne, Offset::NORTH));
+    let n = if passable(map, &current.nw, &current.ne) {
+        Some(Joint::new(nnw, nne, current.nw, current.ne))
+    } else { None };
+
+
+    let ssw = current.sw.and_then(|sw| map.step(sw, Offset::SOUTH));
+    let sse = current.se.and_then(|se| map.step(se, Offset::SOUTH));
+    let s = if passable(map, &current.sw, &current.se) {
+        Some(Joint::new(current.sw, current.se, ssw, sse))
+    } else { None };
+
+    let ene = current.ne.and_then(|ne| map.step(ne, Offset::EAST));
+    let ese = current.se.and_then(|se| map.step(se, Offset::EAST));
+    let e = if passable(map, &current.ne, &current.se) {
+        Some(Joint::new(current.ne, ene, current.se, ese))
+    } else { None };
+
+    let wnw = current.nw.and_then(|nw| map.step(nw, Offset::WEST));
+    let wsw = current.sw.and_then(|sw| map.step(sw, Offset::WEST));
+    let w = if passable(map, &current.nw, &current.sw) {
+        Some(Joint::new(wnw, current.nw, wsw, current.sw))
+    } else { None };
+
+    return [n, s, e, w].into_iter().flatten().collect_vec();
+}
+
+fn passable(map: &Pipes, maybe_a: &Option<Pos>, maybe_b: &Option<Pos>) -> bool {
+    match maybe_a {
+        Some(a) => {
+            match maybe_b {
+                Some(b) => !map[*a].contains(b),
+                None => true
+            }
+        }
+        None => true
+    }
+}
+
+
+fn next_paths(map: &Pipes, mut path: LinkedList<Pos>) -> Vec<LinkedList<Pos>> {
+    let position = path.back().unwrap();
+
+    let prev_position = if path.len() > 1 { path.iter().nth_back(1) } else { None };
+    let maybe_nexts = map.get(*position);
+    match maybe_nexts {
+        None => vec![],
+        Some(nexts) => {
+            let valid_nexts = nexts.iter().filter(|next| match prev_position {
+                None => true,
+                Some(prev) => *next != prev
+            }).cloned().collect_vec();
+
+            if valid_nexts.len() == 1 {
+                let head = valid_nexts.first().unwrap();
+                path.push_back(*head);
+                vec![path]
+            } else {
+                valid_nexts.iter().map(|next| {
+                    let mut new_path = path.clone();
+                    new_path.push_back(*next);
+                    new_path
+                }).collect_vec()
+            }
+        }
+    }
+}
+
+fn is_loop(path: &LinkedList<Pos>) -> bool {
+    let last = path.back().unwrap();
+    let pos = path.iter().find_position(|p| *p == last).unwrap().0;
+    return pos != path.len() - 1;
+}
+
+fn filter_bidirectional(map: &Pipes) -> Pipes
+{
+    map.map(|loc, edges| {
+        edges.iter().filter(|e| map[**e].contains(&loc)).copied().collect_vec()
+    })
+}
+
+/// The cells each tile's pipe leads to, where they are inside the map.
+fn connections(tiles: &Grid<char>) -> Pipes
+{
+    tiles.map(|pos, c| {
+        let offsets: &[Offset] = match c {
+            '|' => &[Offset::NORTH, Offset::SOUTH],
+            '-' => &[Offset::EAST, Offset::WEST],
+            'L' => &[Offset::NORTH, Offset::EAST],
+            'J' => &[Offset::NORTH, Offset::WEST],
+            '7' => &[Offset::SOUTH, Offset::WEST],
+            'F' => &[Offset::SOUTH, Offset::EAST],
+            'S' => &[Offset::NORTH, Offset::SOUTH, Offset::WEST, Offset::EAST],
+            _ => &[],
+        };
+        tiles.neighbors(pos, offsets).collect_vec()
+    })
+}
+
+// General
+/// The tiles as they appear in the input.
+pub fn parse_input(file_content: &str) -> Result<Grid<char>, ParseError>
+{
+    Grid::parse(file_content, "a tile (`|-LJ7F.S`)", |c| Some(c).filter(|c| "|-LJ7F.S".contains(*c)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_map() {
+        let input = Day10::parse(include_str!("../data/example3.txt")).unwrap();
+        let map = render_map(&input, false).unwrap();
+
+        assert_eq!(4, map.matches('I').count());
+        assert_eq!(" ┃II┃ ┃II┃ ", map.lines().nth(6).unwrap());
+        assert!(!map.contains('\u{1b}'));
+    }
+}
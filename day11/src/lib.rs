@@ -0,0 +1,110 @@
+//! Day 11: Cosmic Expansion
+
+use std::cmp::{max, min};
+use aoc_core::{Answer, Grid, ParseError, Pos, Solution};
+use itertools::{Itertools};
+use crate::Space::Void;
+use crate::Space::Galaxy;
+
+/// A cell of the image; `Void(n)` is empty space as wide as `n` cells.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Space {
+    Void(usize),
+    Galaxy,
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Grid<Space>;
+
+    fn parse(file_content: &str) -> Result<Grid<Space>, ParseError> {
+        parse_input(file_content)
+    }
+
+    fn part1(input: &Grid<Space>) -> Option<Answer> {
+        Some(distance_sum(input, 2).into())
+    }
+
+    fn part2(input: &Grid<Space>) -> Option<Answer> {
+        Some(distance_sum(input, 1000000).into())
+    }
+}
+
+/// The sum of the distances between all pairs of galaxies once empty rows and columns grow `factor` times.
+pub fn distance_sum(input: &Grid<Space>, factor: usize) -> usize {
+    let universe = expand(input, factor);
+    let galaxies = galaxies(&universe);
+    let galaxy_pairs = pairs(galaxies);
+    galaxy_pairs.into_iter().map(|(a, b)| distance(&universe, a, b)).sum::<usize>()
+}
+
+/// The distance between two cells walking along rows and columns.
+pub fn distance(universe: &Grid<Space>, a: Pos, b: Pos) -> usize
+{
+    let min_col = min(a.col, b.col);
+    let max_col = max(a.col, b.col);
+    let min_row = min(a.row, b.row);
+    let max_row = max(a.row, b.row);
+
+    let cols = &universe.row(min_row)[min_col..max_col];
+    (min_row..max_row)
+        .map(|row| &universe[Pos::new(row, min_col)]).chain(cols).map(|s| match s {
+        Void(n) => *n,
+        Galaxy => 1
+    }).sum()
+}
+
+pub fn galaxies(space: &Grid<Space>) -> Vec<Pos> {
+    space.iter().flat_map(|(pos, s)| {
+        match s {
+            Void(_) => None,
+            Galaxy => Some(pos)
+        }
+    }).collect_vec()
+}
+
+/// Every unordered pair of galaxies.
+pub fn pairs(galaxies: Vec<Pos>) -> Vec<(Pos, Pos)> {
+    if galaxies.len() < 2 {
+        vec![]
+    } else {
+        let mut iter = galaxies.into_iter();
+        let head = iter.next().unwrap();
+        let tail = iter.collect_vec();
+        let mut result =
+            tail.iter()
+                .map(|t| (head, *t))
+                .collect_vec();
+        let mut tail_pairs = pairs(tail);
+        result.append(&mut tail_pairs);
+        result
+    }
+}
+
+/// Grows every row and column without a galaxy to `factor` cells.
+pub fn expand(space: &Grid<Space>, factor: usize) -> Grid<Space> {
+    let expand_col = space.columns().map(|mut col| col.all(is_void)).collect_vec();
+    let expand_row = space.rows().map(|row| row.iter().all(is_void)).collect_vec();
+
+    space.map(|pos, s| if expand_row[pos.row] || expand_col[pos.col] { Void(factor) } else { s.clone() })
+}
+
+fn is_void(s: &Space) -> bool {
+    match s {
+        Void(_) => true,
+        Galaxy => false
+    }
+}
+
+// General
+pub fn parse_input(file_content: &str) -> Result<Grid<Space>, ParseError>
+{
+    Grid::parse(file_content, "`#` or `.`", |c| match c {
+        '#' => Some(Galaxy),
+        '.' => Some(Void(1)),
+        _ => None
+    })
+}
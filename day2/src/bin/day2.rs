@@ -1,99 +1,126 @@
+use std::collections::BTreeSet;
 use std::fs::read_to_string;
-use regex::{Regex};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use aoc_core::{InputArgs, InputSource, Solution};
+use clap::{Parser, Subcommand};
+use day2::estimate::estimate;
+use day2::{colors, format_bag, game_stat, minimal_bag, parse_bag, parse_games, possible, puzzle_bag, Cubes, Day2, Game};
 
-#[derive(Debug)]
-struct Game {
-    id: u32,
-    reveals: Vec<Reveal>,
+#[derive(Parser)]
+#[command(about = "Solves day 2 of Advent of Code 2023", long_about = None, args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    input: InputArgs,
+    /// The bag of part 1, such as `red=12,green=13,blue=14` [default: the puzzle's bag]
+    #[arg(long, conflicts_with = "bag_file")]
+    bag: Option<String>,
+    /// A file with the bag of part 1, one `<color>=<count>` per line or separated by commas
+    #[arg(long)]
+    bag_file: Option<PathBuf>,
+    /// Instead of the answers, print the IDs of the games that are possible with the bag
+    #[arg(long)]
+    list_possible: bool,
+    /// Instead of the answers, print the smallest bag with which exactly these games are possible, such as `1,2,5`
+    #[arg(long, value_delimiter = ',', conflicts_with = "list_possible")]
+    minimal_bag_for: Option<Vec<u32>>,
+    /// Instead of the answers, print the most likely bag of every game and flag games whose reveals
+    /// no single bag explains
+    #[arg(long, conflicts_with_all = ["list_possible", "minimal_bag_for"])]
+    estimate: bool,
+    /// The most cubes a bag may hold when estimating
+    #[arg(long, default_value_t = 100, requires = "estimate")]
+    max_cubes: u32,
 }
 
-#[derive(Debug)]
-struct Reveal {
-    red: u32,
-    green: u32,
-    blue: u32,
+#[derive(Subcommand)]
+enum Command {
+    /// Print the games with single spaces and each reveal's colors in alphabetical order
+    Fmt {
+        /// Puzzle input file or `-` for stdin [default: $AOC_DAY2_INPUT, then the bundled input]
+        input: Option<String>,
+    },
 }
 
-#[derive(Debug)]
-struct GameStat {
-    id: u32,
-    max_red: u32,
-    max_green: u32,
-    max_blue: u32,
+fn format_games(source: &InputSource) -> Result<(), String> {
+    let file_content = aoc_core::read(source)?;
+    let games = parse_games(&file_content).map_err(|err| err.in_file(source).to_string())?;
+    games.iter().for_each(|game| println!("{}", game));
+    Ok(())
 }
 
-fn main() {
-    let result = read_to_string("day2/data/input.txt").map(parse_games);
-    match result {
-        Ok(games) => {
-            let game_stats = games.iter().map(|game| game_stat(game)).collect::<Vec<GameStat>>();
-            let possible_game_stats = possible(&game_stats, 12, 13, 14);
-            let possible_games_sum = possible_game_stats.iter().map(|stat| stat.id).sum::<u32>();
-            println!("{:?}", possible_games_sum);
-            let sum_of_power = game_stats.iter().map(|stat| stat.max_red * stat.max_green * stat.max_blue).sum::<u32>();
-            println!("{:?}", sum_of_power);
+fn print_estimates(games: &Vec<Game>, max_cubes: u32) {
+    let colors = colors(games);
+    for game in games {
+        match estimate(game, &colors, max_cubes) {
+            Some(estimate) => {
+                let bag = estimate.bag.iter()
+                    .map(|(color, count)| {
+                        let (low, high) = estimate.intervals[color];
+                        return format!("{}={} ({}..={})", color, count, low, high);
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let verdict = if estimate.consistent() { "" } else { ", inconsistent with any single bag" };
+                println!("Game {}: {}, p={:.3}{}", game.id, bag, estimate.p_value, verdict);
+            }
+            None => println!("Game {}: needs more than {} cubes", game.id, max_cubes),
         }
-        Err(err) => { println!("{}", err); }
     }
 }
 
-fn possible(stats: &Vec<GameStat>, red: u32, green: u32, blue: u32) -> Vec<&GameStat> {
-    return stats
-        .iter()
-        .filter(|stat| stat.max_red <= red && stat.max_green <= green && stat.max_blue <= blue)
-        .collect::<Vec<&GameStat>>();
+/// Reads the `--bag-file`, if any, whose text the bag borrows its colors from.
+fn read_bag_file(args: &Args) -> Result<Option<String>, String> {
+    args.bag_file.as_ref()
+        .map(|path| read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err)))
+        .transpose()
 }
 
+fn bag<'a>(args: &'a Args, bag_file_content: Option<&'a str>) -> Result<Cubes<'a>, String> {
+    match (&args.bag, &args.bag_file, bag_file_content) {
+        (Some(spec), _, _) => parse_bag(spec).map_err(|err| err.in_file("--bag").to_string()),
+        (None, Some(path), Some(text)) => parse_bag(text).map_err(|err| err.in_file(path.display()).to_string()),
+        _ => Ok(puzzle_bag()),
+    }
+}
 
-fn game_stat(game: &Game) -> GameStat {
-    return GameStat {
-        id: game.id,
-        max_red: game.reveals.iter().map(|reveal| reveal.red).max().unwrap_or_default(),
-        max_green: game.reveals.iter().map(|reveal| reveal.green).max().unwrap_or_default(),
-        max_blue: game.reveals.iter().map(|reveal| reveal.blue).max().unwrap_or_default()
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let source = args.input.source(Day2::DAY);
+    let solved = match &args.command {
+        Some(Command::Fmt { input }) => format_games(&InputSource::resolve(Day2::DAY, input.as_deref())),
+        None => solve(&args, &source),
     };
-}
 
-fn parse_games(file_content: String) -> Vec<Game>
-{
-    return file_content
-        .lines()
-        .into_iter()
-        .filter(|line| !line.is_empty())
-        .map(parse_game)
-        .collect();
+    match solved {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
 }
 
-fn parse_game(line: &str) -> Game
-{
-    let game_regex = Regex::new(r"^Game (?<id>\d+): (?<reveals>.+)$").unwrap();
-    // Game 1: 2 blue, 4 green; 7 blue, 1 red, 14 green; 5 blue, 13 green, 1 red; 1 red, 7 blue, 11 green
-    return game_regex.captures(line).map(|captures|
-        {
-            let id = captures["id"].parse::<u32>().expect("Game has no id");
-            let reveal_match = captures["reveals"].to_string();
-            let reveals = parse_reveals(reveal_match.as_str());
-            return Game { id, reveals };
-        }).expect("Failed to parse game");
-}
+fn solve(args: &Args, source: &InputSource) -> Result<(), String> {
+    args.input.parts::<Day2>().and_then(|parts| {
+        let bag_file_content = read_bag_file(args)?;
+        let bag = bag(args, bag_file_content.as_deref())?;
+        let file_content = aoc_core::read(source)?;
+        let games = Day2::parse_with(&file_content, bag).map_err(|err| err.in_file(source).to_string())?;
+        let stats = games.games.iter().map(game_stat).collect::<Vec<_>>();
 
-fn parse_reveals(line: &str) -> Vec<Reveal>
-{
-    let reveals = line.split("; ").collect::<Vec<&str>>();
-    return reveals.iter().map(|r| parse_reveal(r)).collect();
+        if args.estimate {
+            print_estimates(&games.games, args.max_cubes);
+        } else if let Some(ids) = &args.minimal_bag_for {
+            let ids = ids.iter().copied().collect::<BTreeSet<_>>();
+            println!("{}", format_bag(&minimal_bag(&stats, &ids)?));
+        } else if args.list_possible {
+            possible(&stats, &games.bag).iter().for_each(|stat| println!("{}", stat.id));
+        } else {
+            aoc_core::print_answers::<Day2>(&games, &parts);
+        }
+        Ok(())
+    })
 }
-
-fn parse_reveal(line: &str) -> Reveal
-{
-    let red_regex = Regex::new(r"(?<count>\d+) red").unwrap();
-    let blue_regex = Regex::new(r"(?<count>\d+) blue").unwrap();
-    let green_regex = Regex::new(r"(?<count>\d+) green").unwrap();
-
-    let reveal = Reveal {
-        blue: blue_regex.captures(line).and_then(|cap| cap["count"].parse::<u32>().ok()).unwrap_or_default(),
-        green: green_regex.captures(line).and_then(|cap| cap["count"].parse::<u32>().ok()).unwrap_or_default(),
-        red: red_regex.captures(line).and_then(|cap| cap["count"].parse::<u32>().ok()).unwrap_or_default(),
-    };
-
-    return reveal;
-}
\ No newline at end of file
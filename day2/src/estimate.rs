@@ -0,0 +1,229 @@
+//! Estimates of what is in a game's bag, treating every reveal as a handful of cubes drawn from the
+//! bag without replacement and put back before the next reveal.
+//!
+//! A reveal of `k_c` cubes of each color `c` out of a bag of `N_c` such cubes has the multivariate
+//! hypergeometric likelihood `Π C(N_c, k_c) / C(N, n)`, and a game's likelihood is the product over
+//! its reveals. Since that likelihood can keep growing with the bag, bags are limited to a largest
+//! number of cubes.
+
+use std::collections::{BTreeMap, BTreeSet};
+use crate::{Cubes, Game};
+
+/// The drop in log-likelihood that bounds a 95% profile-likelihood interval, half the 95% quantile
+/// of the chi-squared distribution with one degree of freedom.
+const INTERVAL_DROP: f64 = 3.841 / 2.0;
+
+/// Below this p-value the reveals of a game are taken to be inconsistent with any single bag.
+pub const SIGNIFICANCE: f64 = 0.05;
+
+/// The most likely bag of a game.
+#[derive(Debug)]
+pub struct Estimate<'a> {
+    pub id: u32,
+    pub bag: Cubes<'a>,
+    /// The 95% confidence interval of the count of each color, inclusive and capped by the largest bag.
+    pub intervals: BTreeMap<&'a str, (u32, u32)>,
+    /// How likely reveals that differ this much are if they were all drawn from one bag.
+    pub p_value: f64,
+}
+
+impl Estimate<'_> {
+    pub fn consistent(&self) -> bool {
+        self.p_value >= SIGNIFICANCE
+    }
+}
+
+/// Estimates the bag of `game` among bags of `colors` holding at most `max_cubes` cubes, or `None`
+/// if the game shows more cubes than that.
+pub fn estimate<'a>(game: &Game, colors: &BTreeSet<&'a str>, max_cubes: u32) -> Option<Estimate<'a>> {
+    let model = Model::new(game, colors);
+    let fewest = model.fewest.iter().sum::<u32>();
+    if fewest > max_cubes {
+        return None;
+    }
+
+    let (log_likelihood, best) = model.best(&model.fewest, None, max_cubes);
+    // The profile likelihood of a color falls off on both sides of its most likely count.
+    let intervals = colors.iter().enumerate().map(|(idx, color)| {
+        let within = |count: &u32| {
+            let mut start = model.fewest.clone();
+            start[idx] = *count;
+            return model.best(&start, Some(idx), max_cubes).0 >= log_likelihood - INTERVAL_DROP;
+        };
+        let highest = max_cubes - (fewest - model.fewest[idx]);
+        let low = (model.fewest[idx]..best[idx]).rev().take_while(within).last().unwrap_or(best[idx]);
+        let high = (best[idx] + 1..=highest).take_while(within).last().unwrap_or(best[idx]);
+        return (*color, (low, high));
+    }).collect();
+
+    let p_value = model.homogeneity();
+    let bag = colors.iter().copied().zip(best).collect();
+    return Some(Estimate { id: game.id, bag, intervals, p_value });
+}
+
+/// The counts of a game's reveals by color index.
+struct Model {
+    counts: Vec<Vec<u32>>,
+    sizes: Vec<u32>,
+    fewest: Vec<u32>,
+}
+
+impl Model {
+    fn new(game: &Game, colors: &BTreeSet<&str>) -> Model {
+        let counts = game.reveals.iter()
+            .map(|reveal| colors.iter().map(|color| reveal.count(color)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let sizes = counts.iter().map(|counts| counts.iter().sum()).collect();
+        let fewest = (0..colors.len()).map(|idx| counts.iter().map(|counts| counts[idx]).max().unwrap_or(0)).collect();
+        return Model { counts, sizes, fewest };
+    }
+
+    /// The most likely bag with at least the cubes of `start` and at most `max_cubes`, keeping
+    /// the count of the `fixed` color, along with its log-likelihood.
+    ///
+    /// The log-likelihood is a sum of a concave function per color and a term that depends on the
+    /// size of the bag only, so for every size adding the cube that gains the most is optimal.
+    fn best(&self, start: &[u32], fixed: Option<usize>, max_cubes: u32) -> (f64, Vec<u32>) {
+        let mut bag = start.to_vec();
+        let mut size = bag.iter().sum::<u32>();
+        let mut log_likelihood = (0..bag.len())
+            .map(|idx| self.counts.iter().map(|counts| ln_choose(bag[idx], counts[idx])).sum::<f64>())
+            .sum::<f64>()
+            - self.sizes.iter().map(|n| ln_choose(size, *n)).sum::<f64>();
+        let mut best = (log_likelihood, bag.clone());
+
+        while size < max_cubes {
+            let Some((idx, gain)) = (0..bag.len())
+                .filter(|idx| Some(*idx) != fixed)
+                .map(|idx| (idx, self.gain(self.counts.iter().map(|counts| counts[idx]), bag[idx])))
+                .max_by(|a, b| a.1.total_cmp(&b.1)) else { break };
+            log_likelihood += gain - self.gain(self.sizes.iter().copied(), size);
+            bag[idx] += 1;
+            size += 1;
+
+            if log_likelihood > best.0 {
+                best = (log_likelihood, bag.clone());
+            }
+        }
+        return best;
+    }
+
+    /// `Σ ln C(n + 1, k) - ln C(n, k)` over the `drawn` counts `k`.
+    fn gain(&self, drawn: impl Iterator<Item = u32>, n: u32) -> f64 {
+        drawn.map(|k| ((n + 1) as f64 / (n + 1 - k) as f64).ln()).sum()
+    }
+
+    /// The p-value of a G-test that the colors are in the same proportions in every reveal.
+    ///
+    /// The test assumes draws with replacement, which vary more than draws without, so it errs
+    /// towards calling reveals consistent.
+    fn homogeneity(&self) -> f64 {
+        let total = self.sizes.iter().sum::<u32>() as f64;
+        let color_totals = (0..self.fewest.len())
+            .map(|idx| self.counts.iter().map(|counts| counts[idx]).sum::<u32>())
+            .collect::<Vec<_>>();
+        let g = 2.0 * self.counts.iter().zip(&self.sizes)
+            .flat_map(|(counts, size)| counts.iter().zip(&color_totals).map(move |(count, color_total)| (*count, *size, *color_total)))
+            .filter(|(count, _, _)| *count > 0)
+            .map(|(count, size, color_total)| count as f64 * (count as f64 * total / (size as f64 * color_total as f64)).ln())
+            .sum::<f64>();
+
+        let shown = color_totals.iter().filter(|count| **count > 0).count() as u32;
+        let df = (self.sizes.len() as u32 - 1) * shown.saturating_sub(1);
+        return if df == 0 { 1.0 } else { chi_square_sf(g, df) };
+    }
+}
+
+/// `ln C(n, k)` for `k <= n`.
+fn ln_choose(n: u32, k: u32) -> f64 {
+    (0..k).map(|i| ((n - i) as f64 / (k - i) as f64).ln()).sum()
+}
+
+/// The probability that a chi-squared variable with `df` degrees of freedom exceeds `x`.
+fn chi_square_sf(x: f64, df: u32) -> f64 {
+    upper_gamma(df as f64 / 2.0, x / 2.0)
+}
+
+/// The regularized upper incomplete gamma function `Q(a, x)`, by its series below `a + 1` and by
+/// its continued fraction above.
+fn upper_gamma(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let scale = (a * x.ln() - x - ln_gamma(a)).exp();
+
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..1000 {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term < sum * 1e-15 {
+                break;
+            }
+        }
+        return 1.0 - scale * sum;
+    }
+
+    let tiny = 1e-300;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / tiny;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for n in 1..1000 {
+        let an = -(n as f64) * (n as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = b + an / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+        if (d * c - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    return scale * h;
+}
+
+/// `ln Γ(x)` for `x >= 0.5`, by the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9, 676.520_368_121_885_1, -1_259.139_216_722_402_8,
+        771.323_428_777_653_1, -176.615_029_162_140_6, 12.507_343_278_686_905,
+        -0.138_571_095_265_720_12, 9.984_369_578_019_572e-6, 1.505_632_735_149_311_6e-7,
+    ];
+    let x = x - 1.0;
+    let sum = COEFFICIENTS[1..].iter().enumerate()
+        .fold(COEFFICIENTS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.0));
+    let t = x + 7.5;
+    return 0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_games;
+
+    #[test]
+    fn test_estimate() {
+        assert!((chi_square_sf(3.841, 1) - 0.05).abs() < 1e-3);
+        assert!((chi_square_sf(10.0, 4) - 0.0404).abs() < 1e-3);
+        assert!((ln_gamma(5.0) - 24f64.ln()).abs() < 1e-9);
+
+        let games = parse_games("Game 1: 1 red, 1 blue\nGame 2: 10 red; 10 blue; 10 red; 10 blue\nGame 3: 30 red\n").unwrap();
+        let colors = BTreeSet::from(["blue", "red"]);
+
+        let first = estimate(&games[0], &colors, 20).unwrap();
+        assert_eq!(Cubes::from([("blue", 1), ("red", 1)]), first.bag);
+        assert_eq!((1, 18), first.intervals["red"]);
+        assert!(first.consistent());
+
+        assert!(!estimate(&games[1], &colors, 100).unwrap().consistent());
+        assert!(estimate(&games[2], &colors, 20).is_none());
+    }
+}
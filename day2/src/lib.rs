@@ -0,0 +1,265 @@
+//! Day 2: Cube Conundrum
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use aoc_core::{parse_lines, parse_number, Answer, ParseError, Solution};
+
+pub mod estimate;
+pub mod parser;
+
+/// A number of cubes per color, with the colors borrowed from the text they were read from.
+pub type Cubes<'a> = BTreeMap<&'a str, u32>;
+
+/// A game with the handfuls of cubes revealed from the bag, displayed as in the puzzle input.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Game<'a> {
+    pub id: u32,
+    pub reveals: Vec<Reveal<'a>>,
+}
+
+/// One handful of cubes, displayed as `<count> <color>` by color.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Reveal<'a> {
+    pub cubes: Cubes<'a>,
+}
+
+impl Reveal<'_> {
+    /// How many cubes of `color` were shown; colors that were not shown count as zero.
+    pub fn count(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or_default()
+    }
+}
+
+impl Display for Game<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (idx, reveal) in self.reveals.iter().enumerate() {
+            if idx > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", reveal)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Reveal<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (idx, (color, count)) in self.cubes.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", count, color)?;
+        }
+        Ok(())
+    }
+}
+
+/// The most cubes of each color a game revealed at once, i.e. the fewest its bag can hold.
+#[derive(Debug)]
+pub struct GameStat<'a> {
+    pub id: u32,
+    pub max: Cubes<'a>,
+}
+
+impl GameStat<'_> {
+    /// The product of the fewest cubes of each of `colors`, which is zero if a color never showed.
+    pub fn power(&self, colors: &BTreeSet<&str>) -> u32 {
+        colors.iter().map(|color| self.max.get(color).copied().unwrap_or_default()).product()
+    }
+}
+
+/// The games together with the bag part 1 checks them against.
+#[derive(Debug)]
+pub struct Games<'a> {
+    pub games: Vec<Game<'a>>,
+    pub bag: Cubes<'a>,
+}
+
+pub struct Day2;
+
+impl Day2 {
+    /// Reads the games to check against `bag` in part 1.
+    pub fn parse_with<'a>(file_content: &'a str, bag: Cubes<'a>) -> Result<Games<'a>, ParseError> {
+        Ok(Games { games: parse_games(file_content)?, bag })
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Games<'a>;
+
+    fn parse(file_content: &str) -> Result<Games<'_>, ParseError> {
+        Day2::parse_with(file_content, puzzle_bag())
+    }
+
+    fn part1(Games { games, bag }: &Games<'_>) -> Option<Answer> {
+        let game_stats = games.iter().map(game_stat).collect::<Vec<GameStat>>();
+        let possible_game_stats = possible(&game_stats, bag);
+        let possible_games_sum = possible_game_stats.iter().map(|stat| stat.id).sum::<u32>();
+        Some(possible_games_sum.into())
+    }
+
+    fn part2(Games { games, .. }: &Games<'_>) -> Option<Answer> {
+        let game_stats = games.iter().map(game_stat).collect::<Vec<GameStat>>();
+        let colors = colors(games);
+        let sum_of_power = game_stats.iter().map(|stat| stat.power(&colors)).sum::<u32>();
+        Some(sum_of_power.into())
+    }
+}
+
+/// Every color revealed in any of the games.
+pub fn colors<'a>(games: &Vec<Game<'a>>) -> BTreeSet<&'a str> {
+    games.iter()
+        .flat_map(|game| &game.reveals)
+        .flat_map(|reveal| reveal.cubes.keys())
+        .copied()
+        .collect()
+}
+
+/// The games that are possible with `bag`; colors missing from the bag have no cubes.
+pub fn possible<'s, 'a>(stats: &'s Vec<GameStat<'a>>, bag: &Cubes) -> Vec<&'s GameStat<'a>> {
+    return stats
+        .iter()
+        .filter(|stat| stat.max.iter().all(|(color, max)| *max <= bag.get(color).copied().unwrap_or_default()))
+        .collect::<Vec<&GameStat>>();
+}
+
+/// The smallest bag with which exactly the games `ids` are possible.
+///
+/// That is the most cubes of each color any of them needs; any other bag they are possible with
+/// holds at least as many cubes, so a game that is possible with the smallest bag cannot be ruled out.
+pub fn minimal_bag<'a>(stats: &Vec<GameStat<'a>>, ids: &BTreeSet<u32>) -> Result<Cubes<'a>, String> {
+    let unknown = ids.iter().filter(|id| !stats.iter().any(|stat| stat.id == **id)).collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        return Err(format!("there are no games {}", join(unknown)));
+    }
+
+    let mut bag = Cubes::new();
+    for (color, max) in stats.iter().filter(|stat| ids.contains(&stat.id)).flat_map(|stat| &stat.max) {
+        let entry = bag.entry(*color).or_default();
+        *entry = (*entry).max(*max);
+    }
+
+    let also_possible = possible(stats, &bag).into_iter()
+        .map(|stat| stat.id)
+        .filter(|id| !ids.contains(id))
+        .collect::<Vec<_>>();
+    if !also_possible.is_empty() {
+        return Err(format!("games {} are possible with every bag that games {} are possible with", join(also_possible), join(ids)));
+    }
+    return Ok(bag);
+}
+
+fn join<T: ToString>(items: impl IntoIterator<Item = T>) -> String {
+    items.into_iter().map(|item| item.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// The bag of part 1: 12 red, 13 green and 14 blue cubes.
+pub fn puzzle_bag() -> Cubes<'static> {
+    Cubes::from([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+/// Reads a bag such as `red=12,green=13,blue=14`, where entries may also be on separate lines.
+pub fn parse_bag(text: &str) -> Result<Cubes<'_>, ParseError> {
+    let mut bag = Cubes::new();
+    for entry in text.split([',', '\n']).map(str::trim).filter(|entry| !entry.is_empty()) {
+        let (color, count) = entry.split_once('=').ok_or_else(|| ParseError::at(text, entry, "`<color>=<count>`"))?;
+        let color = color.trim_end();
+        if color.is_empty() || !color.chars().all(char::is_alphabetic) {
+            return Err(ParseError::at(text, entry, "a color"));
+        }
+        if bag.insert(color, parse_number::<u32>(text, count.trim_start())?).is_some() {
+            return Err(ParseError::at(text, entry, format!("`{}` only once", color)));
+        }
+    }
+    return Ok(bag);
+}
+
+/// Writes `bag` the way [`parse_bag`] reads it.
+pub fn format_bag(bag: &Cubes) -> String {
+    bag.iter().map(|(color, count)| format!("{}={}", color, count)).collect::<Vec<_>>().join(",")
+}
+
+pub fn game_stat<'a>(game: &Game<'a>) -> GameStat<'a> {
+    let mut max = Cubes::new();
+    for (color, count) in game.reveals.iter().flat_map(|reveal| &reveal.cubes) {
+        let entry = max.entry(*color).or_default();
+        *entry = (*entry).max(*count);
+    }
+    return GameStat { id: game.id, max };
+}
+
+pub fn parse_games(file_content: &str) -> Result<Vec<Game<'_>>, ParseError>
+{
+    return parse_lines(file_content, parse_game);
+}
+
+fn parse_game(line: &str) -> Result<Game<'_>, ParseError>
+{
+    let game = parser::game(line)?;
+    let reveals = game.reveals()
+        .map(|reveal| Reveal { cubes: reveal.cubes().collect() })
+        .collect();
+    return Ok(Game { id: game.id, reveals });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_colors() {
+        let games = parse_games("Game 1: 3 yellow, 4 red; 1 red, 2 green\nGame 2: 2 red, 1 green\n").unwrap();
+        let stats = games.iter().map(game_stat).collect::<Vec<_>>();
+        let bag = Cubes::from([("red", 4), ("green", 2)]);
+
+        assert_eq!(vec!["green", "red", "yellow"], colors(&games).into_iter().collect::<Vec<_>>());
+        assert_eq!(vec![2], possible(&stats, &bag).iter().map(|stat| stat.id).collect::<Vec<_>>());
+        assert_eq!(vec![24, 0], stats.iter().map(|stat| stat.power(&colors(&games))).collect::<Vec<_>>());
+        assert_eq!(2, games[0].reveals[1].count("green"));
+        assert_eq!(0, games[0].reveals[1].count("blue"));
+        assert!(parse_games("Game 1: 3 yellow, red").is_err());
+    }
+
+    #[test]
+    fn test_bags() {
+        let games = parse_games("Game 1: 3 blue, 4 red\nGame 2: 1 red, 2 green\nGame 3: 1 red\n").unwrap();
+        let stats = games.iter().map(game_stat).collect::<Vec<_>>();
+
+        assert_eq!(Ok(puzzle_bag()), parse_bag("red=12, green=13\nblue = 14\n"));
+        assert_eq!("blue=14,green=13,red=12", format_bag(&puzzle_bag()));
+        assert!(parse_bag("red=12,red=1").is_err());
+        assert!(parse_bag("red:12").is_err());
+
+        assert_eq!(Ok(parse_bag("red=1,green=2").unwrap()), minimal_bag(&stats, &BTreeSet::from([2, 3])));
+        assert_eq!(Err("games 3 are possible with every bag that games 1 are possible with".to_string()), minimal_bag(&stats, &BTreeSet::from([1])));
+        assert!(minimal_bag(&stats, &BTreeSet::from([4])).is_err());
+    }
+
+    /// A game id with the cubes of each reveal, owned so that a [`Game`] can borrow from them.
+    fn game() -> impl Strategy<Value = (u32, Vec<BTreeMap<String, u32>>)> {
+        let reveal = prop::collection::btree_map("[a-z]{1,8}", any::<u32>(), 1..5);
+        (any::<u32>(), prop::collection::vec(reveal, 1..5))
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip((id, reveals) in game()) {
+            let reveals = reveals.iter()
+                .map(|cubes| Reveal { cubes: cubes.iter().map(|(color, count)| (color.as_str(), *count)).collect() })
+                .collect();
+            let game = Game { id, reveals };
+            let text = game.to_string();
+            prop_assert_eq!(vec![game], parse_games(&text).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let games = parse_games("Game 3 :  2 red,1 blue ;4 green\n").unwrap();
+        assert_eq!("Game 3: 1 blue, 2 red; 4 green", games[0].to_string());
+    }
+}
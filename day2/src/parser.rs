@@ -0,0 +1,179 @@
+//! A hand-written parser for `Game <id>: <count> <color>, ...; ...` lines that borrows from the
+//! input instead of copying it.
+//!
+//! Tokens may be separated by any amount of spaces or tabs, so `Game 1:3 blue ,4 red` is read like
+//! `Game 1: 3 blue, 4 red`.
+
+use std::collections::BTreeSet;
+use aoc_core::{parse_number, ParseError};
+
+/// A checked game line, whose reveals are read from the line on demand.
+#[derive(Debug, Clone, Copy)]
+pub struct GameText<'a> {
+    pub id: u32,
+    reveals: &'a str,
+}
+
+impl<'a> GameText<'a> {
+    /// The reveals in the order of the line.
+    pub fn reveals(&self) -> impl Iterator<Item = RevealText<'a>> {
+        self.reveals.split(';').map(|text| RevealText { text })
+    }
+}
+
+/// One checked reveal of a game line.
+#[derive(Debug, Clone, Copy)]
+pub struct RevealText<'a> {
+    text: &'a str,
+}
+
+impl<'a> RevealText<'a> {
+    /// The colors and counts in the order of the line; every color occurs once.
+    pub fn cubes(&self) -> impl Iterator<Item = (&'a str, u32)> {
+        // The text has been checked by `game`, so every entry is a count and a color.
+        self.text.split(',').map(|cubes| {
+            let (count, color) = cubes.trim().split_once([' ', '\t']).unwrap();
+            return (color.trim_start(), count.parse().unwrap());
+        })
+    }
+}
+
+/// Checks `line` and returns its game, or the first malformed fragment.
+pub fn game(line: &str) -> Result<GameText<'_>, ParseError> {
+    let mut parser = Parser { line, rest: line };
+    parser.skip_space();
+    parser.tag("Game")?;
+    parser.space()?;
+    let id = parser.number("a game id")?;
+    parser.skip_space();
+    parser.tag(":")?;
+    let reveals = parser.rest;
+
+    loop {
+        parser.reveal()?;
+        if parser.rest.is_empty() {
+            return Ok(GameText { id, reveals });
+        }
+        parser.tag(";")?;
+    }
+}
+
+struct Parser<'a> {
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    /// One or more `<count> <color>` separated by commas, up to a `;` or the end of the line.
+    fn reveal(&mut self) -> Result<(), ParseError> {
+        let mut seen = SeenColors::default();
+        loop {
+            self.skip_space();
+            self.number("a cube count")?;
+            self.space()?;
+            let color = self.color()?;
+            if !seen.insert(color) {
+                return Err(ParseError::at(self.line, color, format!("`{}` only once per reveal", color)));
+            }
+            self.skip_space();
+
+            match self.rest.chars().next() {
+                Some(',') => self.rest = &self.rest[1..],
+                None | Some(';') => return Ok(()),
+                Some(_) => return Err(ParseError::at(self.line, self.rest, "`,`, `;` or the end of the line")),
+            }
+        }
+    }
+
+    fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        match self.rest.strip_prefix(tag) {
+            Some(rest) => self.rest = rest,
+            None => return Err(ParseError::at(self.line, self.rest, format!("`{}`", tag))),
+        }
+        return Ok(());
+    }
+
+    fn number(&mut self, expected: &str) -> Result<u32, ParseError> {
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            return Err(ParseError::at(self.line, digits, expected));
+        }
+        return parse_number::<u32>(self.line, digits);
+    }
+
+    fn color(&mut self) -> Result<&'a str, ParseError> {
+        let color = self.take_while(char::is_alphabetic);
+        if color.is_empty() {
+            return Err(ParseError::at(self.line, color, "a color"));
+        }
+        return Ok(color);
+    }
+
+    fn space(&mut self) -> Result<(), ParseError> {
+        if self.skip_space().is_empty() {
+            return Err(ParseError::at(self.line, self.rest, "a space"));
+        }
+        return Ok(());
+    }
+
+    fn skip_space(&mut self) -> &'a str {
+        self.take_while(|c| c == ' ' || c == '\t')
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let end = self.rest.find(|c| !pred(c)).unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        return taken;
+    }
+}
+
+/// The colors of a reveal so far, kept on the stack for the few colors a reveal usually has.
+#[derive(Default)]
+struct SeenColors<'a> {
+    inline: [&'a str; 8],
+    len: usize,
+    spilled: BTreeSet<&'a str>,
+}
+
+impl<'a> SeenColors<'a> {
+    /// Adds `color` and returns whether it was new.
+    fn insert(&mut self, color: &'a str) -> bool {
+        if self.inline[..self.len].contains(&color) {
+            return false;
+        }
+        if self.len < self.inline.len() {
+            self.inline[self.len] = color;
+            self.len += 1;
+            return true;
+        }
+        return self.spilled.insert(color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game() {
+        let game = game("Game 12:3 blue ,4 red;\t1 red").unwrap();
+        let reveals = game.reveals().map(|reveal| reveal.cubes().collect::<Vec<_>>()).collect::<Vec<_>>();
+
+        assert_eq!(12, game.id);
+        assert_eq!(vec![vec![("blue", 3), ("red", 4)], vec![("red", 1)]], reveals);
+
+        let column = |line: &str| super::game(line).map(|_| ()).map_err(|err| (err.column, err.expected));
+        assert_eq!(Err((1, "`Game`".to_string())), column("Gme 1: 1 red"));
+        assert_eq!(Err((6, "a game id".to_string())), column("Game x: 1 red"));
+        assert_eq!(Err((11, "a color".to_string())), column("Game 1: 1 2 red"));
+        assert_eq!(Err((22, "`,`, `;` or the end of the line".to_string())), column("Game 1: 1 red, 2 blue."));
+        assert_eq!(Err((26, "`red` only once per reveal".to_string())), column("Game 1: 1 red, 2 blue, 3 red; 4 red"));
+        assert_eq!(Err((15, "a cube count".to_string())), column("Game 1: 1 red;"));
+        assert_eq!(Ok(()), column("Game 1: 1 red, 2 blue; 3 red, 4 blue"));
+
+        let many = (b'a'..=b'j').map(|c| format!("1 {}", c as char)).collect::<Vec<_>>().join(", ");
+        assert_eq!(Ok(()), column(&format!("Game 1: {}", many)));
+        assert_eq!(Err((61, "`i` only once per reveal".to_string())), column(&format!("Game 1: {}, 2 i", many)));
+    }
+}
@@ -0,0 +1,347 @@
+//! Day 3: Gear Ratios
+
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use aoc_core::{Answer, Grid, Offset, ParseError, Pos, Solution};
+use itertools::Itertools;
+use tokenizer::{Token, TokenKind, Tokenizer};
+
+pub mod tokenizer;
+
+/// A part number where it stands in the schematic, from column `start` up to but not including `end`.
+///
+/// Two part numbers are the same only if they are at the same place, whatever their values.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Span {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+/// A cell of the engine schematic; every digit of a part number holds the whole number.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Entry {
+    PartNumber(Span),
+    Symbol(char),
+    Empty,
+}
+
+/// Which cells around a symbol are next to it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Adjacency {
+    /// The eight cells around it, diagonals included.
+    Surrounding,
+    /// The four cells above, below, left and right of it.
+    Orthogonal,
+    /// Every cell at most this many rows and columns away.
+    Radius(usize),
+}
+
+impl Adjacency {
+    /// The offsets of the cells next to a symbol in `grid`.
+    ///
+    /// A radius is clamped to the size of the grid, since no cell is further away than that.
+    pub fn offsets<T>(&self, grid: &Grid<T>) -> Vec<Offset> {
+        match self {
+            Adjacency::Surrounding => Offset::SURROUNDING.to_vec(),
+            Adjacency::Orthogonal => Offset::ORTHOGONAL.to_vec(),
+            Adjacency::Radius(radius) => {
+                let rows = (*radius).min(grid.height().saturating_sub(1)) as isize;
+                let cols = (*radius).min(grid.width().saturating_sub(1)) as isize;
+                return (-rows..=rows)
+                    .cartesian_product(-cols..=cols)
+                    .filter(|(row, col)| (*row, *col) != (0, 0))
+                    .map(|(row, col)| Offset { row, col })
+                    .collect();
+            }
+        }
+    }
+}
+
+impl FromStr for Adjacency {
+    type Err = String;
+
+    /// Reads `surrounding`, `orthogonal` or `radius=<n>`.
+    fn from_str(s: &str) -> Result<Adjacency, String> {
+        match s {
+            "surrounding" => Ok(Adjacency::Surrounding),
+            "orthogonal" => Ok(Adjacency::Orthogonal),
+            _ => s.strip_prefix("radius=")
+                .and_then(|radius| radius.parse().ok())
+                .map(Adjacency::Radius)
+                .ok_or_else(|| format!("expected `surrounding`, `orthogonal` or `radius=<n>`, got `{}`", s)),
+        }
+    }
+}
+
+/// How the part numbers next to a gear make its ratio.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Combine {
+    Product,
+    Sum,
+}
+
+impl Combine {
+    /// The ratio of a gear next to `part_numbers`, or `None` if it does not fit in 64 bits.
+    pub fn apply(&self, mut part_numbers: impl Iterator<Item = u32>) -> Option<u64> {
+        match self {
+            Combine::Product => part_numbers.try_fold(1u64, |ratio, n| ratio.checked_mul(u64::from(n))),
+            Combine::Sum => part_numbers.try_fold(0u64, |ratio, n| ratio.checked_add(u64::from(n))),
+        }
+    }
+}
+
+impl FromStr for Combine {
+    type Err = String;
+
+    /// Reads `product` or `sum`.
+    fn from_str(s: &str) -> Result<Combine, String> {
+        match s {
+            "product" => Ok(Combine::Product),
+            "sum" => Ok(Combine::Sum),
+            _ => Err(format!("expected `product` or `sum`, got `{}`", s)),
+        }
+    }
+}
+
+/// Which symbols are gears, how many part numbers a gear is next to and how they make its ratio.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GearRule {
+    pub symbols: BTreeSet<char>,
+    pub parts: RangeInclusive<usize>,
+    pub combine: Combine,
+}
+
+impl Default for GearRule {
+    /// The puzzle's gears: a `*` next to exactly two part numbers, whose ratio is their product.
+    fn default() -> GearRule {
+        GearRule { symbols: BTreeSet::from(['*']), parts: 2..=2, combine: Combine::Product }
+    }
+}
+
+/// Reads a number of part numbers as `<n>`, `<min>..=<max>` or `<min>..`.
+pub fn parse_arity(s: &str) -> Result<RangeInclusive<usize>, String> {
+    let expected = || format!("expected `<n>`, `<min>..=<max>` or `<min>..`, got `{}`", s);
+    let number = |n: &str| n.parse::<usize>().map_err(|_| expected());
+    return match (s.split_once("..="), s.strip_suffix("..")) {
+        (Some((min, max)), _) => {
+            let (min, max) = (number(min)?, number(max)?);
+            if min > max {
+                return Err(format!("expected `<min>..=<max>` with `<min>` at most `<max>`, got `{}`", s));
+            }
+            Ok(min..=max)
+        }
+        (None, Some(min)) => Ok(number(min)?..=usize::MAX),
+        (None, None) => number(s).map(|n| n..=n),
+    };
+}
+
+/// The engine schematic together with which cells count as next to a symbol and what a gear is.
+#[derive(Debug)]
+pub struct Schematic {
+    pub grid: Grid<Entry>,
+    pub adjacency: Adjacency,
+    pub gear_rule: GearRule,
+}
+
+pub struct Day3;
+
+impl Day3 {
+    /// Reads the engine schematic to search for part numbers next to symbols by `adjacency`, and
+    /// for gears by `gear_rule`.
+    pub fn parse_with(file_content: &str, adjacency: Adjacency, gear_rule: GearRule) -> Result<Schematic, ParseError> {
+        Ok(Schematic { grid: parse_engine_schematic(file_content)?, adjacency, gear_rule })
+    }
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+
+    fn parse(file_content: &str) -> Result<Schematic, ParseError> {
+        Day3::parse_with(file_content, Adjacency::Surrounding, GearRule::default())
+    }
+
+    fn part1(Schematic { grid, adjacency, .. }: &Schematic) -> Option<Answer> {
+        part_number_sum(grid, *adjacency).map(Answer::from)
+    }
+
+    fn part2(Schematic { grid, adjacency, gear_rule }: &Schematic) -> Option<Answer> {
+        gear_ratio_sum(grid, *adjacency, gear_rule).map(Answer::from)
+    }
+}
+
+// Day 1
+/// The sum of the part numbers adjacent to a symbol, counting a number once per adjacent symbol,
+/// or `None` if it does not fit in 64 bits.
+pub fn part_number_sum(engine_schematic: &Grid<Entry>, adjacency: Adjacency) -> Option<u64> {
+    let offsets = adjacency.offsets(engine_schematic);
+    engine_schematic
+        .iter()
+        .flat_map(|(pos, entry)| symbol_part_numbers(engine_schematic, &offsets, pos, entry))
+        .try_fold(0u64, |sum, part_number| sum.checked_add(u64::from(part_number)))
+}
+
+fn symbol_part_numbers(engine_schematic: &Grid<Entry>, offsets: &[Offset], pos: Pos, entry: &Entry) -> Vec<u32> {
+    match entry {
+        Entry::Symbol(_) =>
+            {
+                let adjacent = adjacents(engine_schematic, offsets, pos);
+                return adjacent.iter().map(|span| span.value).collect();
+            }
+        _ => vec![]
+    }
+}
+
+
+// Day 2
+/// The sum of the ratios of every gear, such as the products of the two part numbers adjacent to
+/// every `*` with exactly two of them, or `None` if a ratio or the sum does not fit in 64 bits.
+pub fn gear_ratio_sum(engine_schematic: &Grid<Entry>, adjacency: Adjacency, gear_rule: &GearRule) -> Option<u64> {
+    let offsets = adjacency.offsets(engine_schematic);
+    engine_schematic
+        .iter()
+        .map(|(pos, entry)| gear_ratio(engine_schematic, &offsets, gear_rule, pos, entry))
+        .try_fold(0u64, |sum, ratio| sum.checked_add(ratio?))
+}
+
+fn gear_ratio(engine_schematic: &Grid<Entry>, offsets: &[Offset], gear_rule: &GearRule, pos: Pos, entry: &Entry) -> Option<u64> {
+    match entry {
+        Entry::Symbol(c) if gear_rule.symbols.contains(c) =>
+            {
+                let adjacent = adjacents(engine_schematic, offsets, pos);
+                if !gear_rule.parts.contains(&adjacent.len()) {
+                    return Some(0);
+                }
+
+                return gear_rule.combine.apply(adjacent.iter().map(|span| span.value));
+            }
+        _ => Some(0)
+    }
+}
+
+// General
+
+/// The part numbers at `offsets` from `pos` within the schematic, each once however many of its
+/// digits are.
+fn adjacents(engine_schematic: &Grid<Entry>, offsets: &[Offset], pos: Pos) -> Vec<Span> {
+    let adjacent = engine_schematic.neighbors(pos, offsets)
+        .filter_map(|neighbor| match engine_schematic[neighbor] {
+            Entry::PartNumber(span) => Some(span),
+            _ => None
+        })
+        .unique()
+        .collect_vec();
+
+    return adjacent;
+}
+
+pub fn parse_engine_schematic(file_content: &str) -> Result<Grid<Entry>, ParseError>
+{
+    let engine_schematic = Grid::parse_rows(file_content, parse_engine_schematic_line)?;
+    return Ok(engine_schematic.map(|pos, entry| match entry {
+        Entry::PartNumber(span) => Entry::PartNumber(Span { row: pos.row, ..*span }),
+        _ => *entry
+    }));
+}
+
+fn parse_engine_schematic_line(line: &str) -> Result<Vec<Entry>, ParseError>
+{
+    let mut entries = Vec::with_capacity(line.len());
+    for token in Tokenizer::new(line) {
+        let Token { start, end, kind } = token?;
+        let entry = match kind {
+            TokenKind::Number(value) => Entry::PartNumber(Span { row: 0, start, end, value }),
+            TokenKind::Symbol(c) => Entry::Symbol(c),
+        };
+        entries.resize(start, Entry::Empty);
+        entries.resize(end, entry);
+    }
+    entries.resize(line.chars().count(), Entry::Empty);
+    return Ok(entries);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_identity() {
+        // Two part numbers of the same value next to each other in the order neighbors are visited.
+        let twins = parse_engine_schematic("..5\n5*.\n").unwrap();
+        assert_eq!(Some(10), part_number_sum(&twins, Adjacency::Surrounding));
+        assert_eq!(Some(25), gear_ratio_sum(&twins, Adjacency::Surrounding, &GearRule::default()));
+
+        // One part number next to a symbol with several of its digits, above and below.
+        let long = parse_engine_schematic("123.\n.*..\n..45\n").unwrap();
+        assert_eq!(Some(168), part_number_sum(&long, Adjacency::Surrounding));
+        assert_eq!(Some(5535), gear_ratio_sum(&long, Adjacency::Surrounding, &GearRule::default()));
+        assert_eq!(Entry::PartNumber(Span { row: 2, start: 2, end: 4, value: 45 }), long[Pos::new(2, 3)]);
+    }
+
+    #[test]
+    fn test_corners() {
+        let corners = parse_engine_schematic("*...#\n.1.2.\n.....\n.3.4.\n$...&\n").unwrap();
+
+        assert_eq!(Some(10), part_number_sum(&corners, Adjacency::Surrounding));
+        assert_eq!(Some(0), part_number_sum(&corners, Adjacency::Orthogonal));
+        assert_eq!(Some(10), part_number_sum(&corners, Adjacency::Radius(2)));
+        assert_eq!(Some(40), part_number_sum(&corners, Adjacency::Radius(3)));
+        assert_eq!(Some(0), part_number_sum(&corners, Adjacency::Radius(0)));
+        assert_eq!(Some(40), part_number_sum(&corners, Adjacency::Radius(usize::MAX)));
+        assert_eq!(9 * 9 - 1, Adjacency::Radius(usize::MAX).offsets(&corners).len());
+
+        let edges = parse_engine_schematic("1*2\n*3*\n4*5\n").unwrap();
+        assert_eq!(Some(36), part_number_sum(&edges, Adjacency::Orthogonal));
+        assert_eq!(Ok(Adjacency::Radius(2)), "radius=2".parse());
+        assert!("radius".parse::<Adjacency>().is_err());
+    }
+
+    #[test]
+    fn test_gear_rules() {
+        let schematic = parse_engine_schematic("2.3.4\n.*.#.\n5...6\n").unwrap();
+        let rule = |symbols: &str, parts: &str, combine: Combine| GearRule {
+            symbols: symbols.chars().collect(),
+            parts: parse_arity(parts).unwrap(),
+            combine,
+        };
+
+        assert_eq!(Some(0), gear_ratio_sum(&schematic, Adjacency::Surrounding, &GearRule::default()));
+        assert_eq!(Some(30), gear_ratio_sum(&schematic, Adjacency::Surrounding, &rule("*", "3", Combine::Product)));
+        assert_eq!(Some(30 + 72), gear_ratio_sum(&schematic, Adjacency::Surrounding, &rule("*#", "3..", Combine::Product)));
+        assert_eq!(Some(10 + 13), gear_ratio_sum(&schematic, Adjacency::Surrounding, &rule("*#", "2..=3", Combine::Sum)));
+        assert!(parse_arity("2..x").is_err());
+        assert_eq!(Err("expected `<min>..=<max>` with `<min>` at most `<max>`, got `5..=2`".to_string()), parse_arity("5..=2"));
+        assert_eq!(Ok(2..=2), parse_arity("2..=2"));
+
+        let overflow = parse_engine_schematic("999.999.999
+...........
+999.*...999
+...........
+999.999.999
+").unwrap();
+        let all = rule("*", "1..", Combine::Product);
+        assert_eq!(None, gear_ratio_sum(&overflow, Adjacency::Radius(4), &all));
+        assert_eq!(Some(8 * 999), gear_ratio_sum(&overflow, Adjacency::Radius(4), &rule("*", "1..", Combine::Sum)));
+        let schematic = Schematic { grid: overflow, adjacency: Adjacency::Radius(4), gear_rule: all };
+        assert_eq!(None, Day3::part2(&schematic));
+    }
+
+    #[test]
+    fn test_long_lines() {
+        let line = "1.€".repeat(100_000);
+        let schematic = parse_engine_schematic(&format!("{}\n{}\n", line, line)).unwrap();
+        assert_eq!(300_000, schematic.width());
+        assert_eq!(Some(399_996), part_number_sum(&schematic, Adjacency::Surrounding));
+
+        let line = "4294967295*".repeat(3);
+        let wide = parse_engine_schematic(&format!("{}\n{}\n{}\n", line, line, line)).unwrap();
+        assert_eq!(Some(35 * u64::from(u32::MAX)), part_number_sum(&wide, Adjacency::Surrounding));
+
+        let err = parse_engine_schematic("1.€\n.\u{0}.\n").unwrap_err();
+        assert_eq!((2, 2), (err.line, err.column));
+    }
+}
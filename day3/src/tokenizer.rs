@@ -0,0 +1,90 @@
+//! Splits a line of the engine schematic into part numbers and symbols in one pass.
+
+use std::iter::Peekable;
+use std::str::CharIndices;
+use aoc_core::ParseError;
+
+/// What a token is.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TokenKind {
+    Number(u32),
+    Symbol(char),
+}
+
+/// A part number or a symbol at the columns `start..end` of its line, counted in characters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub kind: TokenKind,
+}
+
+/// The tokens of a line in order, skipping the `.` between them.
+///
+/// Any visible character other than a digit or `.` is a symbol, so symbols may be non-ASCII;
+/// whitespace, control characters and non-ASCII digits cannot be read.
+pub struct Tokenizer<'a> {
+    line: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    column: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(line: &'a str) -> Tokenizer<'a> {
+        Tokenizer { line, chars: line.char_indices().peekable(), column: 0 }
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Result<Token, ParseError>;
+
+    fn next(&mut self) -> Option<Result<Token, ParseError>> {
+        loop {
+            let (idx, c) = self.chars.next()?;
+            let start = self.column;
+            self.column += 1;
+
+            match c {
+                '.' => continue,
+                '0'..='9' => {
+                    let mut end = idx + 1;
+                    while let Some((digit_idx, _)) = self.chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                        end = digit_idx + 1;
+                        self.column += 1;
+                    }
+                    let digits = &self.line[idx..end];
+                    let token = digits.parse::<u32>()
+                        .map(|value| Token { start, end: self.column, kind: TokenKind::Number(value) })
+                        .map_err(|_| ParseError::at(self.line, digits, "a part number below 2^32"));
+                    return Some(token);
+                }
+                c if c.is_whitespace() || c.is_control() || c.is_numeric() => {
+                    return Some(Err(ParseError::at(self.line, &self.line[idx..], "a digit, `.` or a symbol")));
+                }
+                c => return Some(Ok(Token { start, end: start + 1, kind: TokenKind::Symbol(c) })),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenizer() {
+        let tokens = Tokenizer::new("€12..#\u{2605}7").collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(vec![
+            Token { start: 0, end: 1, kind: TokenKind::Symbol('€') },
+            Token { start: 1, end: 3, kind: TokenKind::Number(12) },
+            Token { start: 5, end: 6, kind: TokenKind::Symbol('#') },
+            Token { start: 6, end: 7, kind: TokenKind::Symbol('\u{2605}') },
+            Token { start: 7, end: 8, kind: TokenKind::Number(7) },
+        ], tokens);
+
+        let err = Tokenizer::new("€€.1\t*").find_map(Result::err).unwrap();
+        assert_eq!((1, 5, "a digit, `.` or a symbol".to_string()), (err.line, err.column, err.expected));
+        let err = Tokenizer::new(".99999999999").find_map(Result::err).unwrap();
+        assert_eq!(2, err.column);
+    }
+}
@@ -0,0 +1,109 @@
+//! Day 4: Scratchcards
+
+use std::collections::HashSet;
+use aoc_core::{parse_lines, parse_number, Answer, ParseError, Solution};
+use itertools::{Itertools};
+
+/// A scratchcard.
+#[derive(Debug)]
+pub struct Card {
+    pub winning_numbers: HashSet<u32>,
+    pub your_numbers: HashSet<u32>,
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+
+    fn parse(file_content: &str) -> Result<Vec<Card>, ParseError> {
+        parse_cards(file_content)
+    }
+
+    fn part1(cards: &Vec<Card>) -> Option<Answer> {
+        Some(cards.iter().map(points).sum::<u64>().into())
+    }
+
+    fn part2(cards: &Vec<Card>) -> Option<Answer> {
+        Some(resolve(cards).into())
+    }
+}
+
+// Day 1
+/// One point for the first match, doubled for every further one.
+pub fn points(card: &Card) -> u64
+{
+    let matches = matches(card);
+
+    if matches == 0 {
+        return 0;
+    }
+
+    return 2u64.pow(matches - 1);
+}
+
+/// How many of your numbers are winning numbers.
+pub fn matches(card: &Card) -> u32 {
+    u32::try_from(card.your_numbers.intersection(&card.winning_numbers).count()).unwrap()
+}
+
+
+// Day 2
+/// The number of scratchcards played once every won copy has been played as well.
+pub fn resolve(cards: &Vec<Card>) -> u64
+{
+
+    let initial_deck = cards.iter().map(|_| 1u64).collect_vec();
+    let initial = (initial_deck, 0u64);
+    let result = cards.iter().fold(initial, |(deck, played_cards), card| {
+
+        let instances = deck.first().unwrap();
+        let matches = usize::try_from(matches(card)).unwrap();
+        let updated_cards = deck.iter().skip(1)
+            .take(matches)
+            .map(|it| it + instances)
+            .collect_vec();
+        let remaining_cards = deck.iter().skip(1 + matches).copied().collect_vec();
+        let new_deck = updated_cards.iter().chain(remaining_cards.iter()).copied().collect_vec();
+        return (new_deck, played_cards + instances);
+    });
+
+    return result.1;
+}
+
+// General
+pub fn parse_cards(file_content: &str) -> Result<Vec<Card>, ParseError>
+{
+    return parse_lines(file_content, parse_card);
+}
+
+fn parse_card(line: &str) -> Result<Card, ParseError>
+{
+    let mut colon_split = line.split(":");
+    let numbers = colon_split.nth(1).ok_or_else(|| ParseError::at_end(line, "`:` followed by numbers"))?;
+
+    let mut numbers_split = numbers.split("|");
+    let winning_numbers = parse_numbers(
+        line,
+        numbers_split
+            .next()
+            .unwrap())?;
+
+    let your_numbers = parse_numbers(
+        line,
+        numbers_split
+            .next()
+            .ok_or_else(|| ParseError::at_end(line, "`|` followed by your numbers"))?)?;
+
+    return Ok(Card { winning_numbers, your_numbers });
+}
+
+fn parse_numbers(line: &str, numbers: &str) -> Result<HashSet<u32>, ParseError>
+{
+    numbers.split(" ")
+        .filter(|num_str| !num_str.is_empty())
+        .map(|num_str| parse_number::<u32>(line, num_str))
+        .collect::<Result<HashSet<u32>, ParseError>>()
+}
@@ -0,0 +1,226 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use aoc_core::{parse_number, Answer, ParseError, Solution};
+use itertools::{Itertools};
+
+/// The numbers `start..start + length`.
+#[derive(Debug, Clone)]
+pub struct Interval {
+    pub start: u64,
+    pub length: u64,
+}
+
+/// One line of a map: numbers in the source interval move by the same offset into the destination interval.
+#[derive(Debug)]
+pub struct Mapping {
+    pub destination_interval: Interval,
+    pub source_interval: Interval,
+}
+
+/// The maps leading from a seed to its location; numbers not covered by a map keep their value.
+#[derive(Debug)]
+pub struct Almanac {
+    pub seed_to_soil: Vec<Mapping>,
+    pub soil_to_fertilizer: Vec<Mapping>,
+    pub fertilizer_to_water: Vec<Mapping>,
+    pub water_to_light: Vec<Mapping>,
+    pub light_to_temperature: Vec<Mapping>,
+    pub temperature_to_humidity: Vec<Mapping>,
+    pub humidity_to_location: Vec<Mapping>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = (Vec<u64>, Almanac);
+
+    fn parse(file_content: &str) -> Result<(Vec<u64>, Almanac), ParseError> {
+        parse_almanac(file_content)
+    }
+
+    fn part1((seeds, almanac): &(Vec<u64>, Almanac)) -> Option<Answer> {
+        let min_location = seeds.iter().map(|seed| seed_location_num(seed, almanac)).min().unwrap();
+        Some(min_location.into())
+    }
+
+    fn part2((seeds, almanac): &(Vec<u64>, Almanac)) -> Option<Answer> {
+        let seed_pairs = seeds.chunks(2).map(|c| Interval { start: c[0], length: c[1] }).collect_vec();
+        let locations = seed_pairs.iter().flat_map(|seed_pair| seeds_location_num(seed_pair, almanac)).collect_vec();
+        let min_location_start = locations.iter().map(|location| location.start).min().unwrap();
+        Some(min_location_start.into())
+    }
+}
+
+
+// Day 1
+/// The location of a single seed.
+pub fn seed_location_num(seed: &u64, almanac: &Almanac) -> u64 {
+    let soil = map_seed(seed, &almanac.seed_to_soil);
+    let fertilizer = map_seed(&soil, &almanac.soil_to_fertilizer);
+    let water = map_seed(&fertilizer, &almanac.fertilizer_to_water);
+    let light = map_seed(&water, &almanac.water_to_light);
+    let temperature = map_seed(&light, &almanac.light_to_temperature);
+    let humidity = map_seed(&temperature, &almanac.temperature_to_humidity);
+    let location = map_seed(&humidity, &almanac.humidity_to_location);
+
+    return location;
+}
+
+/// Maps a number through one map.
+pub fn map_seed(it: &u64, mappings: &Vec<Mapping>) -> u64 {
+    return mappings.iter().find_map(|m| {
+        if *it < m.source_interval.start {
+            return None;
+        }
+
+        let offset = it - m.source_interval.start;
+        if offset < m.source_interval.length {
+            return Some(m.destination_interval.start + offset);
+        }
+
+        None
+    }).unwrap_or(*it);
+}
+
+// Day 2
+/// The locations of an interval of seeds, as the intervals they are split into on the way.
+pub fn seeds_location_num(seeds: &Interval, almanac: &Almanac) -> Vec<Interval> {
+    let soils = map_seeds(seeds, &almanac.seed_to_soil);
+    let fertilizers = soils.iter().flat_map(|soil| map_seeds(soil, &almanac.soil_to_fertilizer)).collect_vec();
+    let waters = fertilizers.iter().flat_map(|fertilizer| map_seeds(fertilizer, &almanac.fertilizer_to_water)).collect_vec();
+    let lights = waters.iter().flat_map(|water| map_seeds(water, &almanac.water_to_light)).collect_vec();
+    let temperatures = lights.iter().flat_map(|light| map_seeds(light, &almanac.light_to_temperature)).collect_vec();
+    let humidities = temperatures.iter().flat_map(|temperature| map_seeds(temperature, &almanac.temperature_to_humidity)).collect_vec();
+    let locations = humidities.iter().flat_map(|humidity| map_seeds(humidity, &almanac.humidity_to_location)).collect_vec();
+    //
+    return locations;
+}
+
+/// Maps an interval through one map, splitting it where the map's intervals start or end.
+pub fn map_seeds(seeds: &Interval, mappings: &Vec<Mapping>) -> Vec<Interval> {
+    let initial_mapped: Vec<Interval> = vec![];
+    let initial_unmapped = vec![seeds.clone()];
+    let initial = (initial_mapped, initial_unmapped);
+
+    let (mapped, unmapped) = mappings.iter().fold(initial, |(mapped, unmapped), m| {
+        let mapping_results = unmapped.iter().map(|interval| map_interval(interval, m)).collect_vec();
+        let additional_mapped = mapping_results.iter().flat_map(|it| &it.0).cloned().collect_vec();
+        let new_unmapped = mapping_results.iter().flat_map(|it| &it.1).cloned().collect_vec();
+
+        let new_mapped = mapped.into_iter().chain(additional_mapped).collect_vec();
+
+        return (new_mapped, new_unmapped);
+    });
+
+    return mapped.into_iter().chain(unmapped).filter(|i| i.length > 0).collect_vec();
+}
+
+fn map_interval(interval: &Interval, mapping: &Mapping) -> (Vec<Interval>, Vec<Interval>) {
+    let source_start = mapping.source_interval.start;
+    let source_end = source_start + mapping.source_interval.length;
+
+    let (maybe_before, maybe_within_or_after) = split_at(interval, source_start);
+    let (maybe_within, maybe_after) = maybe_within_or_after.map_or_else(
+        || (None, None),
+        |within_or_after| split_at(&within_or_after, source_end));
+
+    let transposed_within = maybe_within.map(|within| transpose_interval(within, mapping));
+    let mapped = transposed_within.into_iter().collect_vec();
+    let unmapped = maybe_before.into_iter().chain(maybe_after).collect_vec();
+    return (mapped, unmapped);
+}
+
+fn transpose_interval(interval: Interval, m: &Mapping) -> Interval {
+    let transposed_start = interval.start - m.source_interval.start + m.destination_interval.start;
+    return Interval { start: transposed_start, ..interval };
+}
+
+fn split_at(interval: &Interval, pos: u64) -> (Option<Interval>, Option<Interval>)
+{
+    if pos <= interval.start {
+        return (None, Some(interval.clone()));
+    }
+
+    let interval_end = interval.start + interval.length;
+    if interval_end < pos {
+        return (Some(interval.clone()), None);
+    }
+
+    let before = Some(Interval { start: interval.start, length: pos - interval.start });
+    let after = Some(Interval { start: pos, length: interval_end - pos });
+    return (before, after);
+}
+
+
+// General
+/// The seeds and the almanac.
+pub fn parse_almanac(file_content: &str) -> Result<(Vec<u64>, Almanac), ParseError>
+{
+    let sections = file_content.split("\n\n").collect_vec();
+    let section = |idx: usize, name: &str| sections
+        .get(idx)
+        .copied()
+        .ok_or_else(|| ParseError::at_end(file_content, format!("a `{}` section", name)));
+
+    let seeds_section = section(0, "seeds:")?;
+    let seed_to_soil_section = section(1, "seed-to-soil map:")?;
+    let soil_to_fertilizer_section = section(2, "soil-to-fertilizer map:")?;
+    let fertilizer_to_water_section = section(3, "fertilizer-to-water map:")?;
+    let water_to_light_section = section(4, "water-to-light map:")?;
+    let light_to_temperature_section = section(5, "light-to-temperature map:")?;
+    let temperature_to_humidity_section = section(6, "temperature-to-humidity map:")?;
+    let humidity_to_location_section = section(7, "humidity-to-location map:")?;
+
+    let seeds = seeds_section
+        .split(": ")
+        .last()
+        .unwrap()
+        .split(" ")
+        .map(|it| parse_number::<u64>(file_content, it))
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+    if seeds.len() % 2 != 0 {
+        return Err(ParseError::at(file_content, seeds_section, "an even number of seeds, in pairs of start and length"));
+    }
+
+    let seed_to_soil = parse_mapping(file_content, seed_to_soil_section)?;
+    let soil_to_fertilizer = parse_mapping(file_content, soil_to_fertilizer_section)?;
+    let fertilizer_to_water = parse_mapping(file_content, fertilizer_to_water_section)?;
+    let water_to_light = parse_mapping(file_content, water_to_light_section)?;
+    let light_to_temperature = parse_mapping(file_content, light_to_temperature_section)?;
+    let temperature_to_humidity = parse_mapping(file_content, temperature_to_humidity_section)?;
+    let humidity_to_location = parse_mapping(file_content, humidity_to_location_section)?;
+
+    return Ok((seeds, Almanac {
+        seed_to_soil,
+        soil_to_fertilizer,
+        fertilizer_to_water,
+        water_to_light,
+        light_to_temperature,
+        temperature_to_humidity,
+        humidity_to_location,
+    }));
+}
+
+fn parse_mapping(file_content: &str, str: &str) -> Result<Vec<Mapping>, ParseError>
+{
+    let mapping_lines = str.lines().skip(1).filter(|it| !it.is_empty()).collect_vec();
+    return mapping_lines.iter().map(|it|
+        {
+            let numbers = it.split(" ")
+                .map(|num| parse_number::<u64>(file_content, num))
+                .collect::<Result<Vec<u64>, ParseError>>()?;
+            if numbers.len() != 3 {
+                return Err(ParseError::at(file_content, it, "`<destination start> <source start> <length>`"));
+            }
+            let destination_interval_start = numbers[0];
+            let source_interval_start = numbers[1];
+            let interval_length = numbers[2];
+
+            let destination_interval = Interval { start: destination_interval_start, length: interval_length };
+            let source_interval = Interval { start: source_interval_start, length: interval_length };
+
+            return Ok(Mapping { destination_interval, source_interval });
+        }).collect();
+}
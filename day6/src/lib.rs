@@ -0,0 +1,117 @@
+//! Day 6: Wait For It
+
+use aoc_core::{parse_number, Answer, ParseError, Solution};
+use itertools::Itertools;
+
+/// A race of `time` milliseconds with the record `distance` to beat.
+#[derive(Debug, Clone)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+/// How far the boat got.
+#[derive(Debug, Clone)]
+pub struct RaceResult {
+    pub final_distance: u64,
+}
+
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<Race>;
+
+    fn parse(file_content: &str) -> Result<Vec<Race>, ParseError> {
+        parse_races_day1(file_content)
+    }
+
+    fn part1(races: &Vec<Race>) -> Option<Answer> {
+        let single_wins = races.iter()
+            .map(|race| wins(race).map(|wins| wins.len() as u64))
+            .try_fold(1u64, |product, wins| product.checked_mul(wins?))?;
+        Some(single_wins.into())
+    }
+
+    /// `None` if the joint time or distance does not fit in 64 bits.
+    fn part2(races: &Vec<Race>) -> Option<Answer> {
+        let joint_race_time = races.iter().map(|r| r.time.to_string()).collect::<String>().parse::<u64>().ok()?;
+        let joint_race_distance = races.iter().map(|r| r.distance.to_string()).collect::<String>().parse::<u64>().ok()?;
+        let joint_race = Race { time: joint_race_time, distance: joint_race_distance };
+        let joint_wins = wins(&joint_race)?.len();
+        Some(joint_wins.into())
+    }
+}
+
+/// The results of every button press duration that beats the record, or `None` if a distance
+/// does not fit in 64 bits. The race must take some time, as [`parse_races_day1`] ensures.
+pub fn wins(race: &Race) -> Option<Vec<RaceResult>> {
+    let min_press_duration = race.distance / race.time;
+    let max_press_duration = race.time - 1;
+    let press_durations = min_press_duration..max_press_duration;
+    return press_durations
+        .map(|press_duration| {
+            let remaining_time = race.time - press_duration;
+            let final_distance = press_duration.checked_mul(remaining_time)?;
+            return Some(RaceResult { final_distance });
+        })
+        .filter(|result| result.as_ref().is_none_or(|result| result.final_distance > race.distance))
+        .collect();
+}
+
+// General
+/// One race per column of the `Time:` and `Distance:` lines.
+pub fn parse_races_day1(file_content: &str) -> Result<Vec<Race>, ParseError>
+{
+    let lines = file_content.lines().collect_vec();
+    let time_line = lines.first().ok_or_else(|| ParseError::at_end(file_content, "a `Time:` line"))?;
+    let distance_line = lines.get(1).ok_or_else(|| ParseError::at_end(file_content, "a `Distance:` line"))?;
+
+    let times = parse_values(file_content, time_line)?;
+    let distances = parse_values(file_content, distance_line)?;
+    if let Some((zero, _)) = times.iter().find(|(_, time)| *time == 0) {
+        return Err(ParseError::at(file_content, zero, "a time above zero"));
+    }
+
+    if times.len() != distances.len() {
+        return Err(ParseError::at(file_content, &distance_line[distance_line.len()..], format!("{} distances", times.len())));
+    }
+
+    let races = times
+        .into_iter()
+        .zip(distances)
+        .map(|((_, time), (_, distance))| Race { time, distance })
+        .collect_vec();
+
+    return Ok(races);
+}
+
+/// The numbers after the `:` of `line`, each with the text it was read from.
+fn parse_values<'a>(file_content: &str, line: &'a str) -> Result<Vec<(&'a str, u64)>, ParseError>
+{
+    line.split(":")
+        .nth(1)
+        .ok_or_else(|| ParseError::at(file_content, &line[line.len()..], "`:` followed by numbers"))?
+        .split(" ")
+        .filter(|it| !it.is_empty())
+        .map(|it| parse_number::<u64>(file_content, it).map(|value| (it, value)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits() {
+        let err = Day6::parse("Time: 7 0\nDistance: 9 1\n").unwrap_err();
+        assert_eq!((1, 9, "a time above zero".to_string()), (err.line, err.column, err.expected));
+
+        let races = Day6::parse("Time: 71530 71530 71530 71530\nDistance: 1 1 1 1\n").unwrap();
+        assert_eq!(None, Day6::part2(&races));
+        assert!(wins(&Race { time: u64::MAX, distance: u64::MAX - 1 }).is_none());
+        assert_eq!(Some(Answer::Unsigned(4)), Day6::part1(&Day6::parse("Time: 7\nDistance: 9\n").unwrap()));
+    }
+}
@@ -0,0 +1,315 @@
+//! Day 7: Camel Cards
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::iter::once;
+use aoc_core::{parse_lines, parse_number, Answer, ParseError, Solution};
+use itertools::{Itertools};
+use crate::Card::{Ace, Jack, King, Num, Queen};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Card {
+    Ace,
+    King,
+    Queen,
+    Jack,
+    Num(u32),
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Ace => 'A',
+            King => 'K',
+            Queen => 'Q',
+            Jack => 'J',
+            Num(10) => 'T',
+            Num(n) => n.to_string().chars().next().unwrap()
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// The type of a hand, from strongest to weakest.
+#[derive(Debug, Clone, Eq)]
+pub enum Pattern {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Pattern {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for Pattern {
+    fn cmp(&self, other: &Self) -> Ordering {
+        score_pattern(self).cmp(&score_pattern(other))
+    }
+}
+
+/// A hand of Camel Cards, ordered by pattern and then card by card.
+///
+/// With `jokers`, every `J` is the weakest card but counts as whatever makes the best pattern.
+#[derive(Debug, Clone, Eq)]
+pub struct Hand {
+    pub cards: Vec<Card>,
+    pub jokers: bool,
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cards.iter().map(|c| c.to_string()).collect::<String>())
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let pattern_ord = pattern(self).cmp(&pattern(other));
+        if pattern_ord != Ordering::Equal {
+            return pattern_ord;
+        }
+
+        let cards_ord = self.cards.iter()
+            .zip(other.cards.iter())
+            .map(|(s, o)| score_card(s, self.jokers).cmp(&score_card(o, self.jokers)))
+            .find(|c| *c != Ordering::Equal)
+            .unwrap_or(Ordering::Equal);
+
+        return cards_ord;
+    }
+}
+
+impl Hand {
+    pub fn with_jokers(&self) -> Hand {
+        Hand { jokers: true, ..self.clone() }
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<(Hand, u32)>;
+
+    fn parse(file_content: &str) -> Result<Vec<(Hand, u32)>, ParseError> {
+        parse_hands(file_content)
+    }
+
+    fn part1(hands: &Vec<(Hand, u32)>) -> Option<Answer> {
+        let ranked_hands = ranked_hands(hands.clone());
+        Some(total_score(ranked_hands).into())
+    }
+
+    fn part2(hands: &Vec<(Hand, u32)>) -> Option<Answer> {
+        let joker_hands = hands.iter().map(|(hand, bid)| (hand.with_jokers(), *bid)).collect_vec();
+        let ranked_hands = ranked_hands(joker_hands);
+        Some(total_score(ranked_hands).into())
+    }
+}
+
+/// The sum of every bid multiplied by its rank.
+pub fn total_score(ranked_hands: Vec<(u64, Hand, u64)>) -> u64 {
+    ranked_hands.iter().map(|(rank, _, bid)| rank * bid).sum::<u64>()
+}
+
+/// The hands with their bids from weakest to strongest, as `(rank, hand, bid)` with ranks starting at 1.
+pub fn ranked_hands(hands: Vec<(Hand, u32)>) -> Vec<(u64, Hand, u64)> {
+    hands.into_iter()
+        .sorted_by(|a, b| Ord::cmp(&a.0, &b.0))
+        .enumerate()
+        .map(|(rank, hand)| {
+            (u64::try_from(rank + 1).unwrap(), hand.0, u64::from(hand.1))
+        })
+        .collect_vec()
+}
+
+
+fn score_card(card: &Card, jokers: bool) -> u32 {
+    match card {
+        Ace => 14,
+        King => 13,
+        Queen => 12,
+        Jack if jokers => 1,
+        Jack => 11,
+        Num(n) => *n
+    }
+}
+
+fn score_pattern(pattern: &Pattern) -> u32 {
+    match pattern {
+        Pattern::FiveOfAKind => 20,
+        Pattern::FourOfAKind => 19,
+        Pattern::FullHouse => 18,
+        Pattern::ThreeOfAKind => 17,
+        Pattern::TwoPair => 16,
+        Pattern::OnePair => 15,
+        Pattern::HighCard => 14
+    }
+}
+
+/// The best pattern of `hand`, trying every card for its jokers.
+pub fn pattern(hand: &Hand) -> Pattern {
+    let hands = hands(hand);
+
+    hands.iter().map(|hand| {
+        let counts = hand.cards.iter().counts()
+            .into_iter()
+            .sorted_by(|a, b| Ord::cmp(&b.1, &a.1)).collect_vec();
+
+        let first = *counts.first().unwrap();
+        let second = counts.get(1);
+        match first.1 {
+            5 => Pattern::FiveOfAKind,
+            4 => Pattern::FourOfAKind,
+            3 => match second.unwrap().1 {
+                2 => Pattern::FullHouse,
+                _ => Pattern::ThreeOfAKind
+            },
+            2 => match second.unwrap().1 {
+                2 => Pattern::TwoPair,
+                _ => Pattern::OnePair
+            },
+            _ => Pattern::HighCard
+        }
+    }).max().unwrap()
+}
+
+fn hands(hand: &Hand) -> Vec<Hand> {
+    let maybe_joker = if hand.jokers { hand.cards.iter().position(|c| *c == Jack) } else { None };
+
+    maybe_joker.map_or_else(|| vec![hand.clone()], |pos| {
+        let distinct_non_jokers =
+            hand.cards.iter()
+                .filter(|c| **c != Jack)
+                .chain(once(&Ace))
+                .unique()
+                .collect_vec();
+
+        let prefix = hand
+            .cards
+            .iter()
+            .take(pos)
+            .chain(hand.cards.iter().skip(pos + 1))
+            .cloned()
+            .collect_vec();
+
+        let possible_hands = distinct_non_jokers.iter().map(|c| {
+            Hand { cards: prefix.iter().chain(once(*c)).cloned().collect_vec(), jokers: true }
+        }).collect_vec();
+
+        return possible_hands.iter().flat_map(hands).collect_vec();
+    })
+}
+
+
+// General
+pub fn parse_hands(file_content: &str) -> Result<Vec<(Hand, u32)>, ParseError>
+{
+    return parse_lines(file_content, parse_hand);
+}
+
+/// A hand of exactly five cards and its bid, such as `32T3K 765`.
+pub fn parse_hand(line: &str) -> Result<(Hand, u32), ParseError>
+{
+    let (cards_text, bid) = line.split_once(' ').ok_or_else(|| ParseError::at_end(line, "a space followed by the bid"))?;
+    let cards = cards_text
+        .char_indices()
+        .map(|(idx, c)| parse_card(c).ok_or_else(|| ParseError::at(line, &cards_text[idx..], "a card (`AKQJT98765432`)")))
+        .collect::<Result<Vec<Card>, ParseError>>()?;
+    if cards.len() != 5 {
+        // Every card is a single ASCII character, so the sixth card starts at byte 5.
+        return Err(ParseError::at(line, &cards_text[cards_text.len().min(5)..], "5 cards"));
+    }
+
+    if let Some(extra) = bid.find(' ') {
+        return Err(ParseError::at(line, &bid[extra..], "the end of the line after the bid"));
+    }
+    let bid = parse_number::<u32>(line, bid)?;
+
+    return Ok((Hand { cards, jokers: false }, bid));
+}
+
+fn parse_card(c: char) -> Option<Card> {
+    match c {
+        'A' => Some(Ace),
+        'K' => Some(King),
+        'Q' => Some(Queen),
+        'J' => Some(Jack),
+        'T' => Some(Num(10)),
+        _ => c.to_digit(10).filter(|n| *n >= 2).map(Num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+".to_string();
+
+        let result = parse_hands(&input).unwrap();
+        let ranked_hands = ranked_hands(result);
+
+        assert_eq!(1, ranked_hands[0].0);
+        assert_eq!(2, ranked_hands[1].0);
+        assert_eq!(3, ranked_hands[2].0);
+        assert_eq!(4, ranked_hands[3].0);
+        assert_eq!(5, ranked_hands[4].0);
+
+        assert_eq!(parse_hand("32T3K 765").unwrap().0, ranked_hands[0].1);
+        assert_eq!(parse_hand("KTJJT 220").unwrap().0, ranked_hands[1].1);
+        assert_eq!(parse_hand("KK677 28").unwrap().0, ranked_hands[2].1);
+        assert_eq!(parse_hand("T55J5 684").unwrap().0, ranked_hands[3].1);
+        assert_eq!(parse_hand("QQQJA 483").unwrap().0, ranked_hands[4].1);
+
+        assert_eq!(765, ranked_hands[0].2);
+        assert_eq!(220, ranked_hands[1].2);
+        assert_eq!(28, ranked_hands[2].2);
+        assert_eq!(684, ranked_hands[3].2);
+        assert_eq!(483, ranked_hands[4].2);
+
+        assert_eq!(6440, total_score(ranked_hands));
+    }
+
+    #[test]
+    fn test_malformed_hands() {
+        let error = |line: &str| parse_hand(line).map(|_| ()).map_err(|err| (err.column, err.expected));
+        assert_eq!(Err((6, "5 cards".to_string())), error("JJJJJJJJJJJJJJJJJJJJ 1"));
+        assert_eq!(Err((5, "5 cards".to_string())), error("32T3 765"));
+        assert_eq!(Err((10, "the end of the line after the bid".to_string())), error("32T3K 765 12"));
+        assert_eq!(Err((3, "a card (`AKQJT98765432`)".to_string())), error("321KK 1"));
+        assert_eq!(Err((10, "a space followed by the bid".to_string())), error("32T3K765x"));
+    }
+}
\ No newline at end of file
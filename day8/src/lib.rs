@@ -0,0 +1,134 @@
+//! Day 8: Haunted Wasteland
+
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use aoc_core::{Answer, ParseError, Solution};
+use itertools::{Itertools};
+use regex::{Regex};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Location(pub String);
+
+/// Where each location leads in each direction.
+pub type Network = HashMap<(Location, Direction), Location>;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = (Vec<Direction>, Network);
+
+    fn parse(file_content: &str) -> Result<(Vec<Direction>, Network), ParseError> {
+        parse_input(file_content)
+    }
+
+    fn part1((directions, network): &(Vec<Direction>, Network)) -> Option<Answer> {
+        let start = Location("AAA".to_string());
+        if !network.contains_key(&(start.clone(), Direction::Left)) {
+            return None;
+        }
+        let steps = steps(&start, directions, network);
+        Some(steps.into())
+    }
+
+    fn part2((directions, network): &(Vec<Direction>, Network)) -> Option<Answer> {
+        let ghost_steps = ghost_steps(directions, network);
+        Some(ghost_steps.into())
+    }
+}
+
+// Day1
+/// The number of steps from `start` to the first location ending in `Z`, following `directions` over and over.
+///
+/// `start` and every location it leads to must be in the network, as [`parse_input`] ensures.
+pub fn steps(start: &Location, directions: &Vec<Direction>, network: &Network) -> usize {
+    let direction_iter = iter::repeat(directions).flatten();
+
+    let result = direction_iter
+        .scan(start.clone(), |current, dir| {
+            let new_location = network.get(&(current.clone(), dir.clone()));
+            *current = new_location.unwrap().clone();
+            new_location
+        })
+        .take_while(|Location(current)| !current.ends_with('Z'))
+        .count() + 1;
+
+    result
+}
+
+// Day2
+/// The number of steps until walking from every location ending in `A` at once ends on locations all ending in `Z`.
+pub fn ghost_steps(directions: &Vec<Direction>, network: &Network) -> i64 {
+    let steps = network
+        .keys()
+        .map(|(loc, _)| loc)
+        .unique()
+        .filter(|Location(l)| l.ends_with('A'))
+        .map(|l| steps(l, directions, network))
+        .map(|step| i64::try_from(step).unwrap())
+        .collect_vec();
+
+    return steps.iter().fold(1, |lcm, step| num::integer::lcm(lcm, *step));
+}
+
+// General
+/// The directions and the network.
+pub fn parse_input(file_content: &str) -> Result<(Vec<Direction>, Network), ParseError>
+{
+    let direction_line = file_content.lines().next().filter(|l| !l.is_empty())
+        .ok_or_else(|| ParseError::at(file_content, file_content, "a line of directions"))?;
+    let directions = direction_line.char_indices()
+        .map(|(idx, c)| parse_direction(c).ok_or_else(|| ParseError::at(file_content, &direction_line[idx..], "a direction (`L` or `R`)")))
+        .collect::<Result<Vec<Direction>, ParseError>>()?;
+
+    let network_lines = file_content.lines().skip(2).take_while(|l| !l.is_empty());
+    let network_regex = Regex::new(r"^(?<source>\w\w\w) = \((?<left>\w\w\w), (?<right>\w\w\w)\)$").unwrap();
+
+    let nodes = network_lines.map(|line| {
+        let captures = network_regex.captures(line).ok_or_else(|| ParseError::at(file_content, line, "`AAA = (BBB, CCC)`"))?;
+        let [source, left, right] = ["source", "left", "right"].map(|name| captures.name(name).unwrap().as_str());
+        return Ok((source, left, right));
+    }).collect::<Result<Vec<_>, ParseError>>()?;
+
+    let sources = nodes.iter().map(|(source, _, _)| *source).collect::<HashSet<_>>();
+    if let Some(undefined) = nodes.iter().flat_map(|(_, left, right)| [left, right]).find(|target| !sources.contains(*target)) {
+        return Err(ParseError::at(file_content, undefined, "a location that has a line of its own"));
+    }
+
+    let network = nodes.iter().flat_map(|(source, left, right)| [
+        ((Location(source.to_string()), Direction::Left), Location(left.to_string())),
+        ((Location(source.to_string()), Direction::Right), Location(right.to_string())),
+    ]).collect::<Network>();
+
+    return Ok((directions, network));
+}
+
+fn parse_direction(c: char) -> Option<Direction> {
+    match c {
+        'L' => Some(Direction::Left),
+        'R' => Some(Direction::Right),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undefined_locations() {
+        let input = Day8::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n").unwrap();
+        assert_eq!(None, Day8::part1(&input));
+        assert_eq!(Some(Answer::Signed(1)), Day8::part2(&input));
+
+        let err = parse_input("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!((3, 8), (err.line, err.column));
+    }
+}
@@ -0,0 +1,56 @@
+//! Day 9: Mirage Maintenance
+
+use aoc_core::{parse_number, Answer, ParseError, Solution};
+use itertools::{Itertools};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Vec<i64>>;
+
+    fn parse(file_content: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        parse_input(file_content)
+    }
+
+    fn part1(input: &Vec<Vec<i64>>) -> Option<Answer> {
+        let extrapolated_next_sum = input.iter().map(extrapolate).sum::<i64>();
+        Some(extrapolated_next_sum.into())
+    }
+
+    fn part2(input: &Vec<Vec<i64>>) -> Option<Answer> {
+        let reversed = input.iter().map(|v| v.iter().rev().copied().collect_vec()).collect_vec();
+        let extrapolated_prev_sum = reversed.iter().map(extrapolate).sum::<i64>();
+        Some(extrapolated_prev_sum.into())
+    }
+}
+
+/// The next value of the sequence, found by extrapolating its differences until they are all zero.
+pub fn extrapolate(values: &Vec<i64>) -> i64 {
+    let extrapolated_next = if values.iter().all(|v| *v == 0) {
+        0
+    } else {
+        let differences = values.iter().zip(values.iter().skip(1)).map(|(a, b)| b - a).collect_vec();
+        let extrapolated_next_difference = extrapolate(&differences);
+        let last = values.last().unwrap();
+        last + extrapolated_next_difference
+    };
+
+    return extrapolated_next;
+}
+
+// General
+/// One sequence per line.
+pub fn parse_input(file_content: &str) -> Result<Vec<Vec<i64>>, ParseError>
+{
+    file_content
+        .lines()
+        .take_while(|l| !l.is_empty())
+        .map(|line| line.split(" ").map(|n| parse_number::<i64>(file_content, n)).collect())
+        .collect()
+}
+
+
+
+